@@ -1,9 +1,15 @@
-use ark_std::{rand::Rng, test_rng};
-use criterion::{criterion_group, criterion_main, Criterion};
+use ark_secp256k1::Fq;
+use ark_std::{rand::Rng, test_rng, UniformRand};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 use elliptic_curve::hash_to_curve::{
-    optimized_swu::{secp256K1_oswu::Secp256K1OSWUMap, OptimizedSWUMap},
-    simplified_swu::{secp256K1_sswu::Secp256K1SSWUMap, SimplifiedSWUMap},
-    sw_map::{secp256K1_sw::Secp256K1SWMap, SWMap},
+    constant_time::ConstantTime,
+    field_hasher::XmdFieldHasher,
+    hasher::HashToCurve,
+    optimized_swu::{secp256K1_oswu::Secp256K1OSWUMap, OptimizedSWUHashToCurve, OptimizedSWUMap},
+    simplified_swu::{
+        secp256K1_sswu::Secp256K1SSWUMap, SimplifiedSWUHashToCurve, SimplifiedSWUMap,
+    },
+    sw_map::{secp256K1_sw::Secp256K1SWMap, SWHashToCurve},
 };
 use sha2::Sha256;
 
@@ -13,7 +19,19 @@ fn bench_secp256k1_sw(c: &mut Criterion) {
     group.bench_function("sw".to_string(), |b| {
         b.iter(|| {
             let msg: Vec<u8> = (0..100).map(|_| rng.gen()).collect();
-            _ = Secp256K1SWMap::hash::<Sha256>(&msg);
+            _ = SWHashToCurve::<Secp256K1SWMap>::hash::<XmdFieldHasher<Sha256>>(&msg);
+        });
+    });
+    group.bench_function("sw_encode".to_string(), |b| {
+        b.iter(|| {
+            let msg: Vec<u8> = (0..100).map(|_| rng.gen()).collect();
+            _ = SWHashToCurve::<Secp256K1SWMap>::encode::<XmdFieldHasher<Sha256>>(&msg);
+        });
+    });
+    group.bench_function("sw_ct".to_string(), |b| {
+        b.iter(|| {
+            let msg: Vec<u8> = (0..100).map(|_| rng.gen()).collect();
+            _ = SWHashToCurve::<ConstantTime<Secp256K1SWMap>>::hash::<XmdFieldHasher<Sha256>>(&msg);
         });
     });
     group.finish();
@@ -25,7 +43,23 @@ fn bench_secp256k1_sswu(c: &mut Criterion) {
     group.bench_function("sswu".to_string(), |b| {
         b.iter(|| {
             let msg: Vec<u8> = (0..100).map(|_| rng.gen()).collect();
-            _ = Secp256K1SSWUMap::hash::<Sha256>(&msg);
+            _ = SimplifiedSWUHashToCurve::<Secp256K1SSWUMap>::hash::<XmdFieldHasher<Sha256>>(&msg);
+        });
+    });
+    group.bench_function("sswu_encode".to_string(), |b| {
+        b.iter(|| {
+            let msg: Vec<u8> = (0..100).map(|_| rng.gen()).collect();
+            _ = SimplifiedSWUHashToCurve::<Secp256K1SSWUMap>::encode::<XmdFieldHasher<Sha256>>(
+                &msg,
+            );
+        });
+    });
+    group.bench_function("sswu_ct".to_string(), |b| {
+        b.iter(|| {
+            let msg: Vec<u8> = (0..100).map(|_| rng.gen()).collect();
+            _ = SimplifiedSWUHashToCurve::<ConstantTime<Secp256K1SSWUMap>>::hash::<
+                XmdFieldHasher<Sha256>,
+            >(&msg);
         });
     });
     group.finish();
@@ -37,16 +71,100 @@ fn bench_secp256k1_oswu(c: &mut Criterion) {
     group.bench_function("oswu".to_string(), |b| {
         b.iter(|| {
             let msg: Vec<u8> = (0..100).map(|_| rng.gen()).collect();
-            _ = Secp256K1OSWUMap::hash::<Sha256>(&msg);
+            _ = OptimizedSWUHashToCurve::<Secp256K1OSWUMap>::hash::<XmdFieldHasher<Sha256>>(&msg);
+        });
+    });
+    group.bench_function("oswu_encode".to_string(), |b| {
+        b.iter(|| {
+            let msg: Vec<u8> = (0..100).map(|_| rng.gen()).collect();
+            _ = OptimizedSWUHashToCurve::<Secp256K1OSWUMap>::encode::<XmdFieldHasher<Sha256>>(&msg);
+        });
+    });
+    group.bench_function("oswu_ct".to_string(), |b| {
+        b.iter(|| {
+            let msg: Vec<u8> = (0..100).map(|_| rng.gen()).collect();
+            _ = OptimizedSWUHashToCurve::<ConstantTime<Secp256K1OSWUMap>>::hash::<
+                XmdFieldHasher<Sha256>,
+            >(&msg);
         });
     });
     group.finish();
 }
 
+fn bench_secp256k1_map_to_curve(c: &mut Criterion) {
+    let mut rng = test_rng();
+    let mut group = c.benchmark_group("secp256k1_map_to_curve");
+    group.bench_function("sswu".to_string(), |b| {
+        b.iter(|| {
+            let u = Fq::rand(&mut rng);
+            _ = Secp256K1SSWUMap::map_to_curve(&u);
+        });
+    });
+    group.bench_function("sswu_ct".to_string(), |b| {
+        b.iter(|| {
+            let u = Fq::rand(&mut rng);
+            _ = Secp256K1SSWUMap::map_to_curve_ct(&u);
+        });
+    });
+    group.bench_function("oswu".to_string(), |b| {
+        b.iter(|| {
+            let u = Fq::rand(&mut rng);
+            _ = Secp256K1OSWUMap::map_to_curve(&u);
+        });
+    });
+    group.finish();
+}
+
+fn bench_secp256k1_batch(c: &mut Criterion) {
+    let mut rng = test_rng();
+    let mut group = c.benchmark_group("secp256k1_batch");
+    group.sample_size(10);
+    for size in [1, 10, 100, 1000, 10000] {
+        let msgs: Vec<Vec<u8>> = (0..size)
+            .map(|_| (0..100).map(|_| rng.gen()).collect())
+            .collect();
+        let msgs: Vec<&[u8]> = msgs.iter().map(|msg| msg.as_slice()).collect();
+
+        // the throughput in elements reports the cost per message
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(BenchmarkId::new("sw", size), &msgs, |b, msgs| {
+            b.iter(|| {
+                for msg in msgs.iter() {
+                    _ = SWHashToCurve::<Secp256K1SWMap>::hash::<XmdFieldHasher<Sha256>>(msg);
+                }
+            });
+        });
+        group.bench_with_input(BenchmarkId::new("sw_batch", size), &msgs, |b, msgs| {
+            b.iter(|| {
+                _ = SWHashToCurve::<Secp256K1SWMap>::hash_batch::<XmdFieldHasher<Sha256>>(msgs);
+            });
+        });
+        group.bench_with_input(BenchmarkId::new("sswu", size), &msgs, |b, msgs| {
+            b.iter(|| {
+                for msg in msgs.iter() {
+                    _ = SimplifiedSWUHashToCurve::<Secp256K1SSWUMap>::hash::<
+                        XmdFieldHasher<Sha256>,
+                    >(msg);
+                }
+            });
+        });
+        group.bench_with_input(BenchmarkId::new("sswu_batch", size), &msgs, |b, msgs| {
+            b.iter(|| {
+                _ = SimplifiedSWUHashToCurve::<Secp256K1SSWUMap>::hash_batch::<
+                    XmdFieldHasher<Sha256>,
+                >(msgs);
+            });
+        });
+    }
+    group.finish();
+}
+
 criterion_group!(
     benches,
+    bench_secp256k1_map_to_curve,
     bench_secp256k1_sw,
     bench_secp256k1_sswu,
-    bench_secp256k1_oswu
+    bench_secp256k1_oswu,
+    bench_secp256k1_batch
 );
 criterion_main!(benches);
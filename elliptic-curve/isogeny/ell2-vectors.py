@@ -0,0 +1,189 @@
+#!/usr/bin/env python3
+# Independent implementation of the Elligator 2 map of RFC 9380 (section 6.7.1 and appendix D)
+# with expand_message_xmd SHA-256 in Python integers, which prints the pinned outputs
+# JUBJUB_XMD_SHA256_ELL2_{RO,NU} and BANDERSNATCH_XMD_SHA256_ELL2_{RO,NU} of
+# elliptic-curve/src/hash_to_curve/tests/jubjub.rs
+#
+# usage: python3 ell2-vectors.py
+import hashlib
+
+# the scalar field of BLS12-381, which is the base field of both curves
+p = 0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001
+
+
+def inv(x):
+    return pow(x % p, p - 2, p)
+
+
+def is_square(x):
+    return x % p == 0 or pow(x, (p - 1) // 2, p) == 1
+
+
+def sqrt(x):
+    # Tonelli-Shanks, p = 1 mod 2^32
+    x %= p
+    if x == 0:
+        return 0
+    q, s = p - 1, 0
+    while q % 2 == 0:
+        q //= 2
+        s += 1
+    z = 2
+    while is_square(z):
+        z += 1
+    m, c, t, r = s, pow(z, q, p), pow(x, q, p), pow(x, (q + 1) // 2, p)
+    while t != 1:
+        i, t2 = 0, t
+        while t2 != 1:
+            t2 = t2 * t2 % p
+            i += 1
+        b = pow(c, 1 << (m - i - 1), p)
+        m, c, t, r = i, b * b % p, t * b * b % p, r * b % p
+    assert r * r % p == x
+    return r
+
+
+def sgn0(x):
+    return x % 2
+
+
+def expand_message_xmd(msg, dst, n):
+    # section 5.3.1, b_in_bytes = 32 and s_in_bytes = 64 for SHA-256
+    ell = (n + 31) // 32
+    dst_prime = dst + bytes([len(dst)])
+    msg_prime = bytes(64) + msg + n.to_bytes(2, "big") + b"\0" + dst_prime
+    b0 = hashlib.sha256(msg_prime).digest()
+    b = [hashlib.sha256(b0 + b"\1" + dst_prime).digest()]
+    for i in range(2, ell + 1):
+        xor = bytes(x ^ y for x, y in zip(b0, b[-1]))
+        b.append(hashlib.sha256(xor + bytes([i]) + dst_prime).digest())
+    return b"".join(b)[:n]
+
+
+def hash_to_field(msg, dst, count):
+    # section 5.2, L = ceil((255 + 128) / 8) = 48
+    L = 48
+    uniform_bytes = expand_message_xmd(msg, dst, count * L)
+    return [int.from_bytes(uniform_bytes[i * L:(i + 1) * L], "big") % p for i in range(count)]
+
+
+class Curve:
+    """The twisted Edwards curve a * v^2 + w^2 = 1 + d * v^2 * w^2, which is birationally
+    equivalent to the Montgomery curve K * t^2 = s^3 + J * s^2 + s, with the constant Z
+    of Elligator 2 and the cofactor h"""
+
+    def __init__(self, a, d, J, K, Z, h):
+        self.a, self.d, self.J, self.K, self.Z, self.h = a % p, d % p, J % p, K % p, Z % p, h
+        assert self.a == (J + 2) * inv(K) % p and self.d == (J - 2) * inv(K) % p
+
+    def map_to_curve_elligator2(self, u):
+        # appendix D.3 of RFC 9380 without the constant-time tricks
+        J, K, Z = self.J, self.K, self.Z
+        c1 = J * inv(K) % p
+        c2 = inv(K * K)
+        tv1 = Z * u * u % p
+        e1 = tv1 == p - 1
+        tv1 = 0 if e1 else tv1
+        x1 = -c1 * inv(tv1 + 1) % p
+        gx1 = (x1 * x1 * x1 + c1 * x1 * x1 + c2 * x1) % p
+        x2 = (-x1 - c1) % p
+        gx2 = tv1 * gx1 % p
+        if is_square(gx1):
+            x, y2, e2 = x1, gx1, True
+        else:
+            x, y2, e2 = x2, gx2, False
+        y = sqrt(y2)
+        if (sgn0(y) == 1) != e2:
+            y = -y % p
+        return x * K % p, y * K % p
+
+    def rational_map(self, s, t):
+        # appendix D.1 of RFC 9380, with the exceptional cases sent to the identity
+        if t == 0 or s == p - 1:
+            return (0, 1)
+        return (s * inv(t) % p, (s - 1) * inv(s + 1) % p)
+
+    def add(self, P, Q):
+        x1, y1 = P
+        x2, y2 = Q
+        t = self.d * x1 * x2 * y1 * y2 % p
+        return (
+            (x1 * y2 + y1 * x2) * inv(1 + t) % p,
+            (y1 * y2 - self.a * x1 * x2) * inv(1 - t) % p,
+        )
+
+    def mul(self, k, P):
+        R = (0, 1)
+        while k:
+            if k & 1:
+                R = self.add(R, P)
+            P = self.add(P, P)
+            k >>= 1
+        return R
+
+    def is_on_curve(self, P):
+        x, y = P
+        return (self.a * x * x + y * y - 1 - self.d * x * x * y * y) % p == 0
+
+    def map_to_curve(self, u):
+        P = self.rational_map(*self.map_to_curve_elligator2(u))
+        assert self.is_on_curve(P)
+        return P
+
+
+# JubjubEll2Map and BandersnatchEll2Map
+JUBJUB = Curve(-1, -10240 * inv(10241), 40962, -40964, 5, 8)
+BANDERSNATCH = Curve(
+    -5,
+    45022363124591815672509500913686876175488063829319466900776701791074614335719,
+    29978822694968839326280996386011761570173833766074948509196803838190355340952,
+    25465760566081946422412445027709227188579564747101592991722834452325077642517,
+    5,
+    4,
+)
+
+MSGS = [b"", b"abc", b"abcdef0123456789"]
+
+
+def hex(x):
+    return '"' + format(x, "064x") + '"'
+
+
+def point(P):
+    return "[\n            %s,\n            %s,\n        ]" % (hex(P[0]), hex(P[1]))
+
+
+def vectors(name, curve, dst):
+    out = ["const %s_XMD_SHA256_ELL2_RO: [RoTestVector; %d] = [" % (name, len(MSGS))]
+    for msg in MSGS:
+        u0, u1 = hash_to_field(msg, dst, 2)
+        q0, q1 = curve.map_to_curve(u0), curve.map_to_curve(u1)
+        P = curve.mul(curve.h, curve.add(q0, q1))
+        out.append("    RoTestVector {")
+        out.append('        msg: "%s",' % msg.decode())
+        out.append("        p: %s," % point(P))
+        out.append("        u: %s," % point((u0, u1)))
+        out.append("        q0: %s," % point(q0))
+        out.append("        q1: %s," % point(q1))
+        out.append("    },")
+    out.append("];")
+    out.append("")
+    out.append("const %s_XMD_SHA256_ELL2_NU: [NuTestVector; %d] = [" % (name, len(MSGS)))
+    for msg in MSGS:
+        (u,) = hash_to_field(msg, dst, 1)
+        q = curve.map_to_curve(u)
+        P = curve.mul(curve.h, q)
+        out.append("    NuTestVector {")
+        out.append('        msg: "%s",' % msg.decode())
+        out.append("        p: %s," % point(P))
+        out.append("        u: %s," % hex(u))
+        out.append("        q: %s," % point(q))
+        out.append("    },")
+    out.append("];")
+    return "\n".join(out)
+
+
+if __name__ == "__main__":
+    print(vectors("JUBJUB", JUBJUB, b"jubjub_ell2"))
+    print()
+    print(vectors("BANDERSNATCH", BANDERSNATCH, b"bandersnatch_ell2"))
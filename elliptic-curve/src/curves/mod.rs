@@ -0,0 +1,4 @@
+/// Module for the secp521r1 (P-521) curve,
+/// the derive of `MontConfig` in ark-ff 0.4 expands to non-local impls
+#[allow(non_local_definitions)]
+pub mod secp521r1;
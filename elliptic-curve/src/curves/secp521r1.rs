@@ -0,0 +1,80 @@
+//! The secp521r1 (P-521) curve, which is not provided by arkworks.
+//!
+//! Curve information:
+//! * Base field: q = 2^521 - 1
+//! * Scalar field: r =
+//!   0x1fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffa51868783bf2f966b7fcc0148f709a5d03bb5c9b8899c47aebb6fb71e91386409
+//! * a = -3
+//! * b = 0x51953eb9618e1c9a1f929a21a0b68540eea2da725b99b315f3b8b489918ef109e156193951ec7e937b1652c0bd3bb1bf073573df883d2c34f1ef451fd46b503f00
+//! * Curve equation: y^2 = x^3 + ax + b
+//!
+//! see <https://neuromancer.sk/std/nist/P-521>
+use ark_ec::{
+    models::CurveConfig,
+    short_weierstrass::{self as sw, SWCurveConfig},
+};
+use ark_ff::fields::{Fp576, MontBackend, MontConfig};
+use ark_ff::{Field, MontFp};
+
+#[derive(MontConfig)]
+#[modulus = "6864797660130609714981900799081393217269435300143305409394463459185543183397656052122559640661454554977296311391480858037121987999716643812574028291115057151"]
+#[generator = "3"]
+pub struct FqConfig;
+pub type Fq = Fp576<MontBackend<FqConfig, 9>>;
+
+#[derive(MontConfig)]
+#[modulus = "6864797660130609714981900799081393217269435300143305409394463459185543183397655394245057746333217197532963996371363321113864768612440380340372808892707005449"]
+#[generator = "3"]
+pub struct FrConfig;
+pub type Fr = Fp576<MontBackend<FrConfig, 9>>;
+
+pub type Affine = sw::Affine<Config>;
+pub type Projective = sw::Projective<Config>;
+
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
+pub struct Config;
+
+impl CurveConfig for Config {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+
+    /// COFACTOR = 1
+    const COFACTOR: &'static [u64] = &[0x1];
+
+    /// COFACTOR_INV = COFACTOR^{-1} mod r = 1
+    const COFACTOR_INV: Fr = Fr::ONE;
+}
+
+impl SWCurveConfig for Config {
+    /// COEFF_A = -3
+    const COEFF_A: Fq = MontFp!("-3");
+
+    /// COEFF_B = 1093849038073734274511112390766805569936207598951683748994586394495953116150735016013708737573759623248592132296706313309438452531591012912142327488478985984
+    const COEFF_B: Fq = MontFp!("1093849038073734274511112390766805569936207598951683748994586394495953116150735016013708737573759623248592132296706313309438452531591012912142327488478985984");
+
+    /// GENERATOR = (G_GENERATOR_X, G_GENERATOR_Y)
+    const GENERATOR: Affine = Affine::new_unchecked(G_GENERATOR_X, G_GENERATOR_Y);
+}
+
+/// G_GENERATOR_X =
+/// 2661740802050217063228768716723360960729859168756973147706671368418802944996427808491545080627771902352094241225065558662157113545570916814161637315895999846
+pub const G_GENERATOR_X: Fq = MontFp!("2661740802050217063228768716723360960729859168756973147706671368418802944996427808491545080627771902352094241225065558662157113545570916814161637315895999846");
+
+/// G_GENERATOR_Y =
+/// 3757180025770020463545507224491183603594455134769762486694567779615544477440556316691234405012945539562144444537289428522585666729196580810124344277578376784
+pub const G_GENERATOR_Y: Fq = MontFp!("3757180025770020463545507224491183603594455134769762486694567779615544477440556316691234405012945539562144444537289428522585666729196580810124344277578376784");
+
+#[cfg(test)]
+mod tests {
+    use ark_ec::{AffineRepr, CurveGroup};
+    use ark_ff::PrimeField;
+
+    use super::{Affine, Fr};
+
+    #[test]
+    fn test_generator() {
+        let g = Affine::generator();
+        assert!(g.is_on_curve());
+        assert!(g.mul_bigint(Fr::MODULUS).into_affine().is_zero());
+    }
+}
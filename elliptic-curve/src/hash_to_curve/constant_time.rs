@@ -0,0 +1,162 @@
+use ark_ec::hashing::curve_maps::swu::parity;
+use ark_ff::{BigInteger, Field, PrimeField};
+use ark_std::marker::PhantomData;
+
+/// A wrapper selecting the constant-time `map_to_curve_ct` of the map `M`,
+/// e.g. `ConstantTime<Secp256K1SSWUMap>` hashes with the straight-line implementation.
+///
+/// The straight-line implementations neither branch nor return early on the input,
+/// but they rely on the field arithmetic of arkworks, which is not audited to be constant time.
+///
+/// The wrapper is only a map over prime fields, since `inv0`, `is_square` and `sqrt_ratio`
+/// take their exponents from the prime modulus and the sign sgn0 is the one of prime fields,
+/// e.g. `ConstantTime<Bls12381G2SSWUMap>` over Fp2 does not implement `SimplifiedSWUMap`.
+pub struct ConstantTime<M>(PhantomData<M>);
+
+/// CMOV(a, b, c): return a if c is false, otherwise return b, without branching on c
+pub fn cmov<F: Field>(a: &F, b: &F, c: bool) -> F {
+    let c = F::from(c);
+    b.sub(a).mul(&c).add(a)
+}
+
+/// inv0(x): return the multiplicative inverse of x, and 0 if x is 0,
+/// which is computed by x^(p - 2) with a fixed exponent
+pub fn inv0<F: PrimeField>(x: &F) -> F {
+    let mut exp = F::MODULUS;
+    exp.sub_with_borrow(&F::BigInt::from(2u64));
+    x.pow(exp)
+}
+
+/// is_square(x): return true if x is a square (including 0) in F,
+/// which is computed by x^((p - 1) / 2) with a fixed exponent
+pub fn is_square<F: PrimeField>(x: &F) -> bool {
+    let tv = x.pow(F::MODULUS_MINUS_ONE_DIV_TWO);
+    tv.is_zero() | tv.is_one()
+}
+
+/// sqrt_ratio(u, v): return (true, sqrt(u / v)) if u / v is square in F,
+/// otherwise return (false, sqrt(z * u / v)), where `z` is a non-square in F and v != 0.
+/// For u = 0 the root is 0 while the flag is false.
+///
+/// This is the constant-time algorithm for any prime field of
+/// <https://www.rfc-editor.org/rfc/rfc9380.html#appendix-F.2.1.1>
+pub fn sqrt_ratio<F: PrimeField>(u: &F, v: &F, z: &F) -> (bool, F) {
+    // c1 is the largest integer such that 2^c1 divides p - 1, p - 1 = 2^c1 * c2 and c3 = (c2 - 1) / 2
+    let c1 = F::TWO_ADICITY;
+
+    // c6 = z^c2 and c7 = z^((c2 + 1) / 2)
+    let z_c3: F = z.pow(F::TRACE_MINUS_ONE_DIV_TWO);
+    let c6: F = z_c3.square().mul(z);
+    let c7: F = z_c3.mul(z);
+
+    // tv2 = v^c4, where c4 = 2^c1 - 1
+    let mut tv2: F = *v;
+    for _ in 1..c1 {
+        tv2 = tv2.square().mul(v);
+    }
+
+    let mut tv1: F = c6;
+    let tv3: F = tv2.square().mul(v);
+    let tv5: F = u.mul(&tv3).pow(F::TRACE_MINUS_ONE_DIV_TWO).mul(&tv2);
+    let tv2: F = tv5.mul(v);
+    let mut tv3: F = tv5.mul(u);
+    let mut tv4: F = tv3.mul(&tv2);
+
+    // tv5 = tv4^c5, where c5 = 2^(c1 - 1)
+    let mut tv5: F = tv4;
+    for _ in 1..c1 {
+        tv5.square_in_place();
+    }
+    let is_qr = tv5.is_one();
+
+    let tv2: F = tv3.mul(&c7);
+    let tv5: F = tv4.mul(&tv1);
+    tv3 = cmov(&tv2, &tv3, is_qr);
+    tv4 = cmov(&tv5, &tv4, is_qr);
+
+    for i in (2..=c1).rev() {
+        let mut tv5: F = tv4;
+        for _ in 2..i {
+            tv5.square_in_place();
+        }
+        let e1 = tv5.is_one();
+        let tv2: F = tv3.mul(&tv1);
+        tv1.square_in_place();
+        let tv5: F = tv4.mul(&tv1);
+        tv3 = cmov(&tv2, &tv3, e1);
+        tv4 = cmov(&tv5, &tv4, e1);
+    }
+
+    (is_qr, tv3)
+}
+
+/// The straight-line Simplified SWU map onto y^2 = x^3 + A * x + B with the non-square `z`,
+/// which returns (x_num, x_den, y) of the point (x_num / x_den, y), and x_den is never zero.
+///
+/// see <https://www.rfc-editor.org/rfc/rfc9380.html#section-6.6.2>
+pub fn sswu<F: PrimeField>(u: &F, a: &F, b: &F, z: &F) -> (F, F, F) {
+    let tv1: F = u.square().mul(z);
+    let tv2: F = tv1.square().add(&tv1);
+    let tv3: F = tv2.add(&F::ONE).mul(b);
+    let tv4: F = cmov(z, &tv2.neg(), !tv2.is_zero()).mul(a);
+
+    let tv6: F = tv4.square();
+    let tv5: F = tv6.mul(a);
+    let tv2: F = tv3.square().add(&tv5).mul(&tv3);
+    let tv6: F = tv6.mul(&tv4);
+    let tv5: F = tv6.mul(b);
+    let tv2: F = tv2.add(&tv5);
+
+    let x: F = tv1.mul(&tv3);
+    let (is_gx1_square, y1) = sqrt_ratio(&tv2, &tv6, z);
+    let y: F = tv1.mul(u).mul(&y1);
+    let x: F = cmov(&x, &tv3, is_gx1_square);
+    let y: F = cmov(&y, &y1, is_gx1_square);
+
+    let e1 = parity(u) == parity(&y);
+    let y: F = cmov(&y.neg(), &y, e1);
+
+    (x, tv4, y)
+}
+
+/// The straight-line Simplified SWU map for p = 3 mod 4, which merges sqrt_ratio_3mod4 into the map,
+/// so that it takes a single exponentiation and no inversion.
+/// `c2` is sqrt(-z^3), and it returns (x_num, x_den, y) of the point (x_num / x_den, y).
+///
+/// see <https://www.rfc-editor.org/rfc/rfc9380.html#appendix-G.2.3>
+pub fn sswu_3mod4<F: PrimeField>(u: &F, a: &F, b: &F, z: &F, c2: &F) -> (F, F, F) {
+    debug_assert_eq!(F::TWO_ADICITY, 1);
+
+    let tv1: F = u.square();
+    let tv3: F = z.mul(&tv1);
+    let tv5: F = tv3.square();
+    let xd: F = tv5.add(&tv3);
+    let x1n: F = xd.add(&F::ONE).mul(b);
+    let xd: F = a.neg().mul(&xd);
+    let xd: F = cmov(&xd, &z.mul(a), xd.is_zero());
+
+    let tv2: F = xd.square();
+    let gxd: F = tv2.mul(&xd);
+    let tv2: F = a.mul(&tv2);
+    let gx1: F = x1n.square().add(&tv2).mul(&x1n);
+    let tv2: F = b.mul(&gxd);
+    let gx1: F = gx1.add(&tv2);
+
+    // y1 = (gx1 * gxd^3)^c1 * gx1 * gxd, where c1 = (p - 3) / 4 = (TRACE - 1) / 2 for p = 3 mod 4
+    let tv4: F = gxd.square();
+    let tv2: F = gx1.mul(&gxd);
+    let tv4: F = tv4.mul(&tv2);
+    let y1: F = tv4.pow(F::TRACE_MINUS_ONE_DIV_TWO).mul(&tv2);
+
+    let x2n: F = tv3.mul(&x1n);
+    let y2: F = y1.mul(c2).mul(&tv1).mul(u);
+
+    let e2 = y1.square().mul(&gxd) == gx1;
+    let xn: F = cmov(&x2n, &x1n, e2);
+    let y: F = cmov(&y2, &y1, e2);
+
+    let e3 = parity(u) == parity(&y);
+    let y: F = cmov(&y.neg(), &y, e3);
+
+    (xn, xd, y)
+}
@@ -0,0 +1,24 @@
+use ark_ed_on_bls12_381_bandersnatch::{BandersnatchConfig, Fq};
+use ark_ff::MontFp;
+use sha2::Sha256;
+
+use crate::hash_to_curve::field_hasher::XmdFieldHasher;
+
+use super::Elligator2Map;
+
+/// The Elligator 2 map for Bandersnatch over the scalar field of BLS12-381: -5 * v^2 + w^2 = 1 + d * v^2 * w^2,
+/// which maps to the Montgomery model of arkworks and then to Bandersnatch by the rational map.
+///
+/// Z = 5 is the smallest non-square in absolute value, and the cofactor 4 is cleared by
+/// `Elligator2Map::clear_cofactor`, which multiplies by `H_EFF`.
+pub struct BandersnatchEll2Map;
+
+impl Elligator2Map<BandersnatchConfig> for BandersnatchEll2Map {
+    const Z: Fq = MontFp!("5");
+
+    const DST: &'static [u8] = b"bandersnatch_ell2";
+
+    /// The hash_to_field function of the suites `bandersnatch_XMD:SHA-256_ELL2_RO_`
+    /// and `bandersnatch_XMD:SHA-256_ELL2_NU_`
+    type FieldHasher = XmdFieldHasher<Sha256>;
+}
@@ -0,0 +1,24 @@
+use ark_curve25519::{Curve25519Config, Fq};
+use ark_ff::MontFp;
+use sha2::Sha512;
+
+use crate::hash_to_curve::field_hasher::XmdFieldHasher;
+
+use super::Elligator2Map;
+
+/// The Elligator 2 map for curve25519: t^2 = s^3 + 486662 * s^2 + s.
+///
+/// The points are output on the twisted Edwards model 486664 * v^2 + w^2 = 1 + 486660 * v^2 * w^2 of arkworks,
+/// which is the image of the rational map with c1 = 1,
+/// and `Elligator2Map::edwards_to_montgomery` gives the point (s, t) of the suite.
+pub struct Curve25519Ell2Map;
+
+impl Elligator2Map<Curve25519Config> for Curve25519Ell2Map {
+    const Z: Fq = MontFp!("2");
+
+    const DST: &'static [u8] = b"curve25519_ell2";
+
+    /// The hash_to_field function of the suites `curve25519_XMD:SHA-512_ELL2_RO_`
+    /// and `curve25519_XMD:SHA-512_ELL2_NU_` of RFC 9380
+    type FieldHasher = XmdFieldHasher<Sha512>;
+}
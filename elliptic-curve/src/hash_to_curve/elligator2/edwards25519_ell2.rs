@@ -0,0 +1,33 @@
+use ark_ed25519::{EdwardsConfig, Fq};
+use ark_ff::MontFp;
+use sha2::Sha512;
+
+use crate::hash_to_curve::field_hasher::XmdFieldHasher;
+
+use super::Elligator2Map;
+
+/// The Elligator 2 map for edwards25519: -v^2 + w^2 = 1 - 121665 / 121666 * v^2 * w^2,
+/// which maps to curve25519: t^2 = s^3 + 486662 * s^2 + s and then to edwards25519 by the rational map.
+///
+/// The Montgomery model of arkworks has K = -486664, so J and K are set to the ones of curve25519,
+/// and c1 = sqrt(-486664) with sgn0(c1) = 0 scales the rational map,
+/// see <https://www.rfc-editor.org/rfc/rfc9380.html#appendix-D.2>
+pub struct Edwards25519Ell2Map;
+
+impl Elligator2Map<EdwardsConfig> for Edwards25519Ell2Map {
+    const Z: Fq = MontFp!("2");
+
+    const J: Fq = MontFp!("486662");
+
+    const K: Fq = MontFp!("1");
+
+    const C1: Option<Fq> = Some(MontFp!(
+        "6853475219497561581579357271197624642482790079785650197046958215289687604742"
+    ));
+
+    const DST: &'static [u8] = b"edwards25519_ell2";
+
+    /// The hash_to_field function of the suites `edwards25519_XMD:SHA-512_ELL2_RO_`
+    /// and `edwards25519_XMD:SHA-512_ELL2_NU_` of RFC 9380
+    type FieldHasher = XmdFieldHasher<Sha512>;
+}
@@ -0,0 +1,24 @@
+use ark_ed_on_bls12_381::{Fq, JubjubConfig};
+use ark_ff::MontFp;
+use sha2::Sha256;
+
+use crate::hash_to_curve::field_hasher::XmdFieldHasher;
+
+use super::Elligator2Map;
+
+/// The Elligator 2 map for Jubjub over the scalar field of BLS12-381: -v^2 + w^2 = 1 - 10240 / 10241 * v^2 * w^2,
+/// which maps to the Montgomery curve -40964 * t^2 = s^3 + 40962 * s^2 + s and then to Jubjub by the rational map.
+///
+/// Z = 5 is the smallest non-square in absolute value, and the cofactor 8 is cleared by
+/// `Elligator2Map::clear_cofactor`, which multiplies by `H_EFF`.
+pub struct JubjubEll2Map;
+
+impl Elligator2Map<JubjubConfig> for JubjubEll2Map {
+    const Z: Fq = MontFp!("5");
+
+    const DST: &'static [u8] = b"jubjub_ell2";
+
+    /// The hash_to_field function of the suites `jubjub_XMD:SHA-256_ELL2_RO_`
+    /// and `jubjub_XMD:SHA-256_ELL2_NU_`
+    type FieldHasher = XmdFieldHasher<Sha256>;
+}
@@ -0,0 +1,192 @@
+use ark_ec::hashing::curve_maps::swu::parity;
+use ark_ec::hashing::map_to_curve_hasher::MapToCurve;
+use ark_ec::hashing::HashToCurveError;
+use ark_ec::twisted_edwards::Affine;
+use ark_ec::twisted_edwards::MontCurveConfig;
+use ark_ec::twisted_edwards::Projective;
+use ark_ec::twisted_edwards::TECurveConfig;
+use ark_ec::Group;
+use ark_ff::field_hashers::HashToField;
+use ark_ff::Field;
+use ark_ff::One;
+use ark_ff::Zero;
+use ark_std::marker::PhantomData;
+use ark_std::ops::*;
+use ark_std::string::ToString;
+
+use super::hasher::HashToCurve;
+
+/// Module for the edwards25519 instance of the Elligator 2 map
+pub mod edwards25519_ell2;
+
+/// Module for the curve25519 instance of the Elligator 2 map
+pub mod curve25519_ell2;
+
+/// Module for the Jubjub instance of the Elligator 2 map
+pub mod jubjub_ell2;
+
+/// Module for the Bandersnatch instance of the Elligator 2 map
+pub mod bandersnatch_ell2;
+
+/// Trait for implementing the Elligator 2 method that is effective for
+/// Montgomery equation K * t^2 = s^3 + J * s^2 + s, and for the twisted Edwards equation
+/// a * v^2 + w^2 = 1 + d * v^2 * w^2 which is birationally equivalent to it.
+///
+/// The map outputs the points on the twisted Edwards model `P`, and the Montgomery model
+/// is given by J and K, see <https://www.rfc-editor.org/rfc/rfc9380.html#section-6.7.1>
+pub trait Elligator2Map<P: TECurveConfig> {
+    /// The constant z, a non-square in the field
+    const Z: P::BaseField;
+
+    /// The parameter J of Montgomery curve,
+    /// which is the parameter A of the Montgomery model of `P` by default
+    const J: P::BaseField = <P::MontCurveConfig as MontCurveConfig>::COEFF_A;
+
+    /// The parameter K of Montgomery curve,
+    /// which is the parameter B of the Montgomery model of `P` by default
+    const K: P::BaseField = <P::MontCurveConfig as MontCurveConfig>::COEFF_B;
+
+    /// The constant c1 that scales the v-coordinate of the rational map,
+    /// c1 = sqrt((J + 2) / (K * a)) if the twisted Edwards curve is not a = (J + 2) / K and d = (J - 2) / K,
+    /// and `None` means c1 = 1
+    const C1: Option<P::BaseField> = None;
+
+    /// The default domain separation tag,
+    /// applications should separate their own domains by `hash_with_dst` or a `Hasher`
+    const DST: &'static [u8];
+
+    /// The hash_to_field function of the suite, e.g. `XmdFieldHasher<Sha256>`,
+    /// which `HashToCurve::hash_to_curve` and `HashToCurve::encode_to_curve` use
+    type FieldHasher: HashToField<P::BaseField>;
+
+    /// The effective cofactor h_eff of RFC 9380 in little-endian limbs,
+    /// which is the cofactor of the curve by default
+    const H_EFF: &'static [u64] = P::COFACTOR;
+
+    /// Mapping an arbitrary field element to a point (s, t) on the Montgomery curve
+    ///
+    /// The exceptional inputs with 1 + Z * u^2 = 0 are handled by `inv0`,
+    /// see <https://www.rfc-editor.org/rfc/rfc9380.html#section-6.7.1>
+    fn map_to_montgomery(u: &P::BaseField) -> (P::BaseField, P::BaseField) {
+        let k_inv: P::BaseField = Self::K.inverse().unwrap();
+        let j_div_k: P::BaseField = Self::J.mul(&k_inv);
+        let k2_inv: P::BaseField = k_inv.square();
+
+        let tv1: P::BaseField = u
+            .square()
+            .mul(&Self::Z)
+            .add(&P::BaseField::one())
+            .inverse()
+            .unwrap_or_else(P::BaseField::zero);
+
+        // x1 = -J / K for the exceptional inputs, where tv1 = inv0(0) = 0
+        let x1: P::BaseField = if tv1.is_zero() {
+            j_div_k.neg()
+        } else {
+            j_div_k.neg().mul(&tv1)
+        };
+        let gx1: P::BaseField = x1.add(&j_div_k).mul(&x1).add(&k2_inv).mul(&x1);
+        let (x, y) = if gx1.legendre().is_qr() {
+            let y = gx1.sqrt().unwrap();
+            (x1, if parity(&y) { y } else { -y })
+        } else {
+            let x2: P::BaseField = x1.neg().sub(&j_div_k);
+            let gx2: P::BaseField = x2.add(&j_div_k).mul(&x2).add(&k2_inv).mul(&x2);
+            let y = gx2.sqrt().unwrap();
+            (x2, if parity(&y) { -y } else { y })
+        };
+
+        (x.mul(&Self::K), y.mul(&Self::K))
+    }
+
+    /// The rational map from the Montgomery curve to the twisted Edwards curve,
+    /// v = c1 * s / t and w = (s - 1) / (s + 1), where the exceptional points with t = 0 or s = -1
+    /// map to the identity (0, 1),
+    /// see <https://www.rfc-editor.org/rfc/rfc9380.html#section-6.8.2>
+    fn montgomery_to_edwards(s: &P::BaseField, t: &P::BaseField) -> Affine<P> {
+        let tv1: P::BaseField = s.add(&P::BaseField::one());
+        let tv2: P::BaseField = tv1.mul(t).inverse().unwrap_or_else(P::BaseField::zero);
+        let v: P::BaseField = tv2.mul(&tv1).mul(s);
+        let w: P::BaseField = if tv2.is_zero() {
+            P::BaseField::one()
+        } else {
+            tv2.mul(t).mul(&s.sub(&P::BaseField::one()))
+        };
+
+        let v: P::BaseField = match Self::C1 {
+            Some(c1) => v.mul(&c1),
+            None => v,
+        };
+        Affine::<P>::new_unchecked(v, w)
+    }
+
+    /// The inverse of the rational map, s = (1 + w) / (1 - w) and t = c1 * s / v,
+    /// which returns `None` for the identity (0, 1) that is the point at infinity of the Montgomery curve
+    fn edwards_to_montgomery(point: &Affine<P>) -> Option<(P::BaseField, P::BaseField)> {
+        let den: P::BaseField = P::BaseField::one().sub(&point.y).inverse()?;
+        let s: P::BaseField = P::BaseField::one().add(&point.y).mul(&den);
+        // the point (0, -1) of order 2 maps to (0, 0)
+        let t: P::BaseField = s
+            .mul(&point.x.inverse().unwrap_or_else(P::BaseField::zero))
+            .mul(&Self::C1.unwrap_or_else(P::BaseField::one));
+
+        Some((s, t))
+    }
+
+    /// Mapping an arbitrary field element to a point on the twisted Edwards curve,
+    /// This step matching step 2 and step 3
+    fn map_to_curve(u: &P::BaseField) -> Affine<P> {
+        let (s, t) = Self::map_to_montgomery(u);
+        Self::montgomery_to_edwards(&s, &t)
+    }
+
+    /// Clearing the cofactor of a point on the curve, this step matching step 5 of hashing to curve
+    ///
+    /// The default multiplies by `H_EFF`, the instances on the curves with an endomorphism
+    /// override it with a faster method, e.g. the psi endomorphism of BLS12-381 G2
+    fn clear_cofactor(point: &Projective<P>) -> Projective<P> {
+        point.mul_bigint(Self::H_EFF)
+    }
+}
+
+/// Hashing to curve with the Elligator 2 map `M`, e.g. `Elligator2HashToCurve<Edwards25519Ell2Map>`,
+/// which is the `HashToCurve` of the Elligator 2 map family
+pub struct Elligator2HashToCurve<M>(PhantomData<M>);
+
+impl<P: TECurveConfig, M: Elligator2Map<P>> HashToCurve<Projective<P>>
+    for Elligator2HashToCurve<M>
+{
+    const DST: &'static [u8] = M::DST;
+    type FieldHasher = M::FieldHasher;
+
+    fn map_to_curve(u: &P::BaseField) -> Projective<P> {
+        M::map_to_curve(u).into()
+    }
+
+    fn clear_cofactor(point: &Projective<P>) -> Projective<P> {
+        M::clear_cofactor(point)
+    }
+}
+
+/// The `MapToCurve` of arkworks, so that the Elligator 2 maps can be plugged into
+/// `MapToCurveBasedHasher` and the libraries built on arkworks
+impl<P: TECurveConfig, M: Elligator2Map<P>> MapToCurve<Projective<P>> for Elligator2HashToCurve<M> {
+    fn new() -> Result<Self, HashToCurveError> {
+        if M::Z.legendre().is_qr() {
+            return Err(HashToCurveError::MapToCurveError(
+                "Z should be a quadratic non-residue for the Elligator 2 map".to_string(),
+            ));
+        }
+        if M::K.is_zero() {
+            return Err(HashToCurveError::MapToCurveError(
+                "K should be nonzero for the Elligator 2 map".to_string(),
+            ));
+        }
+
+        Ok(Self(PhantomData))
+    }
+
+    fn map_to_curve(&self, point: P::BaseField) -> Result<Affine<P>, HashToCurveError> {
+        Ok(M::map_to_curve(&point))
+    }
+}
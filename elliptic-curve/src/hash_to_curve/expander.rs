@@ -0,0 +1,155 @@
+use ark_std::{vec, vec::Vec};
+use sha2::digest::core_api::BlockSizeUser;
+use sha2::digest::{DynDigest, ExtendableOutput, Update};
+
+/// The maximum length of a domain separation tag
+const MAX_DST_LENGTH: usize = 255;
+
+/// The prefix for hashing a domain separation tag longer than 255 bytes
+const OVERSIZE_DST_SALT: &[u8] = b"H2C-OVERSIZE-DST-";
+
+/// Trait for expanding a message into a uniformly random byte string,
+///
+/// see <https://www.rfc-editor.org/rfc/rfc9380.html#section-5.3>
+pub trait Expander {
+    /// Expand the message into `len_in_bytes` uniformly random bytes
+    fn expand(&self, msg: &[u8], len_in_bytes: usize) -> Vec<u8>;
+}
+
+/// The expand_message_xmd function, which is built on a Merkle–Damgård hash function such as SHA-2.
+///
+/// see <https://www.rfc-editor.org/rfc/rfc9380.html#section-5.3.1>
+pub struct ExpanderXmd<H: Default + DynDigest + Clone + BlockSizeUser> {
+    hasher: H,
+    dst: Vec<u8>,
+}
+
+impl<H: Default + DynDigest + Clone + BlockSizeUser> ExpanderXmd<H> {
+    /// Create an expander with the domain separation tag `dst`,
+    /// a tag longer than 255 bytes is replaced by H("H2C-OVERSIZE-DST-" || dst).
+    ///
+    /// see <https://www.rfc-editor.org/rfc/rfc9380.html#section-5.3.3>
+    pub fn new(dst: &[u8]) -> Self {
+        let mut hasher = H::default();
+
+        let dst = if dst.len() > MAX_DST_LENGTH {
+            hasher.update(OVERSIZE_DST_SALT);
+            hasher.update(dst);
+            hasher.finalize_reset().to_vec()
+        } else {
+            dst.to_vec()
+        };
+
+        Self { hasher, dst }
+    }
+}
+
+impl<H: Default + DynDigest + Clone + BlockSizeUser> Expander for ExpanderXmd<H> {
+    fn expand(&self, msg: &[u8], len_in_bytes: usize) -> Vec<u8> {
+        let mut hasher = self.hasher.clone();
+        let b_in_bytes = hasher.output_size();
+        let s_in_bytes = H::block_size();
+
+        let ell = len_in_bytes.div_ceil(b_in_bytes);
+        assert!(
+            ell <= 255 && len_in_bytes <= 65535,
+            "The requested output is too long for expand_message_xmd"
+        );
+
+        let dst_prime = dst_prime(&self.dst);
+        let z_pad = vec![0u8; s_in_bytes];
+        let l_i_b_str = (len_in_bytes as u16).to_be_bytes();
+
+        // b_0 = H(Z_pad || msg || l_i_b_str || I2OSP(0, 1) || DST_prime)
+        hasher.update(&z_pad);
+        hasher.update(msg);
+        hasher.update(&l_i_b_str);
+        hasher.update(&[0u8]);
+        hasher.update(&dst_prime);
+        let b_0 = hasher.finalize_reset();
+
+        // b_1 = H(b_0 || I2OSP(1, 1) || DST_prime)
+        hasher.update(&b_0);
+        hasher.update(&[1u8]);
+        hasher.update(&dst_prime);
+        let mut b_i = hasher.finalize_reset();
+
+        let mut uniform_bytes: Vec<u8> = Vec::with_capacity(ell * b_in_bytes);
+        uniform_bytes.extend_from_slice(&b_i);
+
+        // b_i = H(strxor(b_0, b_(i - 1)) || I2OSP(i, 1) || DST_prime)
+        for i in 2..=ell {
+            let xored: Vec<u8> = b_0.iter().zip(b_i.iter()).map(|(l, r)| l ^ r).collect();
+            hasher.update(&xored);
+            hasher.update(&[i as u8]);
+            hasher.update(&dst_prime);
+            b_i = hasher.finalize_reset();
+            uniform_bytes.extend_from_slice(&b_i);
+        }
+
+        uniform_bytes.truncate(len_in_bytes);
+        uniform_bytes
+    }
+}
+
+/// The expand_message_xof function, which is built on an extendable-output function such as SHAKE.
+///
+/// see <https://www.rfc-editor.org/rfc/rfc9380.html#section-5.3.2>
+pub struct ExpanderXof<H: Default + Update + ExtendableOutput + Clone> {
+    hasher: H,
+    dst: Vec<u8>,
+}
+
+impl<H: Default + Update + ExtendableOutput + Clone> ExpanderXof<H> {
+    /// Create an expander with the domain separation tag `dst` and the security level `sec_param` in bits,
+    /// a tag longer than 255 bytes is replaced by H("H2C-OVERSIZE-DST-" || dst, ceil(2 * k / 8)).
+    ///
+    /// see <https://www.rfc-editor.org/rfc/rfc9380.html#section-5.3.3>
+    pub fn new(dst: &[u8], sec_param: usize) -> Self {
+        let dst = if dst.len() > MAX_DST_LENGTH {
+            let mut hasher = H::default();
+            hasher.update(OVERSIZE_DST_SALT);
+            hasher.update(dst);
+
+            let mut hashed_dst = vec![0u8; (2 * sec_param).div_ceil(8)];
+            hasher.finalize_xof_into(&mut hashed_dst);
+            hashed_dst
+        } else {
+            dst.to_vec()
+        };
+
+        Self {
+            hasher: H::default(),
+            dst,
+        }
+    }
+}
+
+impl<H: Default + Update + ExtendableOutput + Clone> Expander for ExpanderXof<H> {
+    fn expand(&self, msg: &[u8], len_in_bytes: usize) -> Vec<u8> {
+        assert!(
+            len_in_bytes <= 65535,
+            "The requested output is too long for expand_message_xof"
+        );
+
+        let mut hasher = self.hasher.clone();
+        let dst_prime = dst_prime(&self.dst);
+        let l_i_b_str = (len_in_bytes as u16).to_be_bytes();
+
+        // uniform_bytes = H(msg || I2OSP(len_in_bytes, 2) || DST_prime, len_in_bytes)
+        hasher.update(msg);
+        hasher.update(&l_i_b_str);
+        hasher.update(&dst_prime);
+
+        let mut uniform_bytes = vec![0u8; len_in_bytes];
+        hasher.finalize_xof_into(&mut uniform_bytes);
+        uniform_bytes
+    }
+}
+
+/// DST_prime = DST || I2OSP(len(DST), 1)
+fn dst_prime(dst: &[u8]) -> Vec<u8> {
+    let mut dst_prime = dst.to_vec();
+    dst_prime.push(dst.len() as u8);
+    dst_prime
+}
@@ -0,0 +1,104 @@
+use ark_ff::field_hashers::HashToField;
+use ark_ff::{Field, PrimeField};
+use ark_std::vec::Vec;
+use sha2::digest::core_api::BlockSizeUser;
+use sha2::digest::{DynDigest, ExtendableOutput, Update};
+
+use super::expander::{Expander, ExpanderXmd, ExpanderXof};
+
+/// The hash_to_field function built on expand_message_xmd,
+/// `SEC_PARAM` is the target security level k in bits.
+///
+/// Unlike `ark_ff::field_hashers::DefaultFieldHasher`, the zero padding of expand_message_xmd
+/// is the input block size of the hash function, so the outputs match the test vectors of RFC 9380.
+///
+/// see <https://www.rfc-editor.org/rfc/rfc9380.html#section-5.2>
+pub struct XmdFieldHasher<
+    H: Default + DynDigest + Clone + BlockSizeUser,
+    const SEC_PARAM: usize = 128,
+> {
+    expander: ExpanderXmd<H>,
+    len_per_base_elem: usize,
+}
+
+impl<F: Field, H: Default + DynDigest + Clone + BlockSizeUser, const SEC_PARAM: usize>
+    HashToField<F> for XmdFieldHasher<H, SEC_PARAM>
+{
+    fn new(dst: &[u8]) -> Self {
+        let expander = ExpanderXmd::<H>::new(dst);
+        let len_per_base_elem = len_per_base_elem::<F>(SEC_PARAM);
+
+        Self {
+            expander,
+            len_per_base_elem,
+        }
+    }
+
+    fn hash_to_field(&self, msg: &[u8], count: usize) -> Vec<F> {
+        hash_to_field(&self.expander, self.len_per_base_elem, msg, count)
+    }
+}
+
+/// The hash_to_field function built on expand_message_xof,
+/// `SEC_PARAM` is the target security level k in bits.
+///
+/// see <https://www.rfc-editor.org/rfc/rfc9380.html#section-5.2>
+pub struct XofFieldHasher<
+    H: Default + Update + ExtendableOutput + Clone,
+    const SEC_PARAM: usize = 128,
+> {
+    expander: ExpanderXof<H>,
+    len_per_base_elem: usize,
+}
+
+impl<F: Field, H: Default + Update + ExtendableOutput + Clone, const SEC_PARAM: usize>
+    HashToField<F> for XofFieldHasher<H, SEC_PARAM>
+{
+    fn new(dst: &[u8]) -> Self {
+        let expander = ExpanderXof::<H>::new(dst, SEC_PARAM);
+        let len_per_base_elem = len_per_base_elem::<F>(SEC_PARAM);
+
+        Self {
+            expander,
+            len_per_base_elem,
+        }
+    }
+
+    fn hash_to_field(&self, msg: &[u8], count: usize) -> Vec<F> {
+        hash_to_field(&self.expander, self.len_per_base_elem, msg, count)
+    }
+}
+
+/// Expand the message and interpret every `len_per_base_elem` bytes as a base field element,
+/// every `m` consecutive base field elements make up one element of the extension field F.
+fn hash_to_field<F: Field, E: Expander>(
+    expander: &E,
+    len_per_base_elem: usize,
+    msg: &[u8],
+    count: usize,
+) -> Vec<F> {
+    let m = F::extension_degree() as usize;
+    let len_in_bytes = count * m * len_per_base_elem;
+    let uniform_bytes = expander.expand(msg, len_in_bytes);
+
+    let mut field_elems: Vec<F> = Vec::with_capacity(count);
+    let mut base_prime_field_elems = Vec::with_capacity(m);
+    for i in 0..count {
+        base_prime_field_elems.clear();
+        for j in 0..m {
+            let elm_offset = len_per_base_elem * (j + i * m);
+            let tv = &uniform_bytes[elm_offset..elm_offset + len_per_base_elem];
+            base_prime_field_elems.push(F::BasePrimeField::from_be_bytes_mod_order(tv));
+        }
+        field_elems.push(F::from_base_prime_field_elems(&base_prime_field_elems).unwrap());
+    }
+
+    field_elems
+}
+
+/// The length L in bytes of the uniform string used for each base field element:
+/// L = ceil((ceil(log2(p)) + k) / 8)
+fn len_per_base_elem<F: Field>(sec_param: usize) -> usize {
+    let base_field_size_in_bits = F::BasePrimeField::MODULUS_BIT_SIZE as usize;
+    (base_field_size_in_bits + sec_param).div_ceil(8)
+}
@@ -0,0 +1,188 @@
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::field_hashers::HashToField;
+use ark_std::marker::PhantomData;
+use ark_std::vec::Vec;
+use ark_std::{cfg_chunks, cfg_iter};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Trait for hashing to curve over all the maps, so that the callers can swap the maps without changing code.
+///
+/// The map families implement it through `SWHashToCurve`, `SimplifiedSWUHashToCurve`,
+/// `OptimizedSWUHashToCurve` and `Elligator2HashToCurve`, e.g. `SWHashToCurve<Secp256K1SWMap>`,
+/// and `G` is the group of the curve, e.g. `Projective<ark_secp256k1::Config>`.
+///
+/// see <https://www.rfc-editor.org/rfc/rfc9380.html#section-3>
+pub trait HashToCurve<G: CurveGroup>: Sized {
+    /// The default domain separation tag,
+    /// applications should separate their own domains by `hash_with_dst` or a `Hasher`
+    const DST: &'static [u8];
+
+    /// The hash_to_field function of the suite of the map, e.g. `XmdFieldHasher<Sha256>`
+    /// for `secp256k1_XMD:SHA-256_SSWU_RO_`
+    type FieldHasher: HashToField<G::BaseField>;
+
+    /// Mapping an arbitrary field element to a point on the curve,
+    /// whose output is not necessarily in the prime order subgroup
+    fn map_to_curve(u: &G::BaseField) -> G;
+
+    /// Mapping the field elements to the points on the curve in affine coordinates,
+    /// the map families share the inversions of the maps among the elements where they can.
+    ///
+    /// The default maps each element and normalizes the outputs together by `CurveGroup::normalize_batch`
+    fn map_to_curve_batch(us: &[G::BaseField]) -> Vec<G::Affine> {
+        let points: Vec<G> = cfg_iter!(us).map(Self::map_to_curve).collect();
+        G::normalize_batch(&points)
+    }
+
+    /// Clearing the cofactor of a point on the curve
+    ///
+    /// The default is `clear_cofactor` of the curve in arkworks,
+    /// which multiplies by the effective cofactor h_eff of RFC 9380 where arkworks provides it
+    fn clear_cofactor(point: &G) -> G {
+        point.into_affine().clear_cofactor().into_group()
+    }
+
+    /// Mapping an arbitrary message to `count` field elements,
+    /// This step matching step 1
+    ///
+    /// `H` is the hash_to_field function, e.g. `XmdFieldHasher<Sha256>` for expand_message_xmd
+    /// or `XofFieldHasher<Shake128>` for expand_message_xof
+    fn hash_to_field<H: HashToField<G::BaseField>>(
+        msg: &[u8],
+        dst: &[u8],
+        count: usize,
+    ) -> Vec<G::BaseField> {
+        let field_hasher = H::new(dst);
+
+        field_hasher.hash_to_field(msg, count)
+    }
+
+    /// Perform hashing to curve
+    ///
+    /// To implement hashing to curve, the following steps are usually required:
+    /// step 1 : u = hash_to_field(msg, 2)
+    /// step 2 : Q0 = map_to_curve(u[0])
+    /// step 3 : Q1 = map_to_curve(u[1])
+    /// step 4 : R = Q0 + Q1
+    /// step 5 : P = clear_cofactor(R)
+    /// step 6 : return P
+    fn hash<H: HashToField<G::BaseField>>(msg: &[u8]) -> G {
+        Self::hash_with_dst::<H>(msg, Self::DST)
+    }
+
+    /// Perform hashing to curve with the domain separation tag `dst`
+    fn hash_with_dst<H: HashToField<G::BaseField>>(msg: &[u8], dst: &[u8]) -> G {
+        Hasher::<G, Self, H>::new(dst).hash(msg)
+    }
+
+    /// Hashing to curve with the suite of the map, i.e. `hash_with_dst` with `FieldHasher`,
+    /// `dst` is the domain separation tag of the application
+    fn hash_to_curve(msg: &[u8], dst: &[u8]) -> G {
+        Self::hash_with_dst::<Self::FieldHasher>(msg, dst)
+    }
+
+    /// Perform hashing to curve for each of the messages, whose outputs are in affine coordinates
+    ///
+    /// The field elements of all the messages are mapped by `map_to_curve_batch`,
+    /// and the outputs are normalized with one inversion by `CurveGroup::normalize_batch`
+    ///
+    /// With the `parallel` feature, hash_to_field, the maps and the cofactor clearing
+    /// spread across the cores by rayon, so `H` is shared among the threads
+    fn hash_batch<H: HashToField<G::BaseField> + Sync>(msgs: &[&[u8]]) -> Vec<G::Affine> {
+        Self::hash_batch_with_dst::<H>(msgs, Self::DST)
+    }
+
+    /// Perform hashing to curve for each of the messages with the domain separation tag `dst`
+    fn hash_batch_with_dst<H: HashToField<G::BaseField> + Sync>(
+        msgs: &[&[u8]],
+        dst: &[u8],
+    ) -> Vec<G::Affine> {
+        Hasher::<G, Self, H>::new(dst).hash_batch(msgs)
+    }
+
+    /// Perform encoding to curve, whose output distribution is nonuniform
+    ///
+    /// To implement encoding to curve, the following steps are usually required:
+    /// step 1 : u = hash_to_field(msg, 1)
+    /// step 2 : Q = map_to_curve(u[0])
+    /// step 3 : P = clear_cofactor(Q)
+    /// step 4 : return P
+    fn encode<H: HashToField<G::BaseField>>(msg: &[u8]) -> G {
+        Self::encode_with_dst::<H>(msg, Self::DST)
+    }
+
+    /// Perform encoding to curve with the domain separation tag `dst`
+    fn encode_with_dst<H: HashToField<G::BaseField>>(msg: &[u8], dst: &[u8]) -> G {
+        Hasher::<G, Self, H>::new(dst).encode(msg)
+    }
+
+    /// Encoding to curve with the suite of the map, i.e. `encode_with_dst` with `FieldHasher`,
+    /// whose output distribution is nonuniform, see section 3 of RFC 9380
+    fn encode_to_curve(msg: &[u8], dst: &[u8]) -> G {
+        Self::encode_with_dst::<Self::FieldHasher>(msg, dst)
+    }
+}
+
+/// A hasher to the curve built from any map `M` implementing `HashToCurve`, the hash_to_field function `H`
+/// and a domain separation tag chosen by the application,
+/// e.g. `Hasher<Projective<ark_secp256k1::Config>, SWHashToCurve<Secp256K1SWMap>, XmdFieldHasher<Sha256>>`.
+///
+/// The field hasher is created once for the tag, and the `*_with_dst` methods of `HashToCurve`
+/// create a hasher per call. A tag longer than 255 bytes is hashed to a short one,
+/// see <https://www.rfc-editor.org/rfc/rfc9380.html#section-5.3.3>
+pub struct Hasher<G: CurveGroup, M: HashToCurve<G>, H: HashToField<G::BaseField>> {
+    field_hasher: H,
+    _marker: PhantomData<(G, M)>,
+}
+
+impl<G: CurveGroup, M: HashToCurve<G>, H: HashToField<G::BaseField>> Hasher<G, M, H> {
+    /// Create a hasher with the domain separation tag `dst`
+    pub fn new(dst: &[u8]) -> Self {
+        Self {
+            field_hasher: H::new(dst),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Perform hashing to curve, whose output distribution is uniform
+    pub fn hash(&self, msg: &[u8]) -> G {
+        let rand_field_elems: Vec<G::BaseField> = self.field_hasher.hash_to_field(msg, 2);
+
+        let rand_curve_elem_0 = M::map_to_curve(&rand_field_elems[0]);
+        let rand_curve_elem_1 = M::map_to_curve(&rand_field_elems[1]);
+
+        let rand_curve_elem: G = rand_curve_elem_0.add(&rand_curve_elem_1);
+        M::clear_cofactor(&rand_curve_elem)
+    }
+
+    /// Perform hashing to curve for each of the messages, whose outputs are in affine coordinates
+    ///
+    /// The field elements of all the messages are mapped by `HashToCurve::map_to_curve_batch`,
+    /// and the outputs are normalized with one inversion by `CurveGroup::normalize_batch`
+    pub fn hash_batch(&self, msgs: &[&[u8]]) -> Vec<G::Affine>
+    where
+        H: Sync,
+    {
+        // the field hasher alone is shared among the threads with the `parallel` feature
+        let field_hasher = &self.field_hasher;
+        let rand_field_elems: Vec<G::BaseField> = cfg_iter!(msgs)
+            .flat_map(|msg| field_hasher.hash_to_field(msg, 2))
+            .collect();
+
+        let rand_curve_elems: Vec<G::Affine> = M::map_to_curve_batch(&rand_field_elems);
+        let rand_curve_elems: Vec<G> = cfg_chunks!(rand_curve_elems, 2)
+            .map(|q| M::clear_cofactor(&(q[0] + q[1])))
+            .collect();
+        G::normalize_batch(&rand_curve_elems)
+    }
+
+    /// Perform encoding to curve, whose output distribution is nonuniform
+    pub fn encode(&self, msg: &[u8]) -> G {
+        let rand_field_elems: Vec<G::BaseField> = self.field_hasher.hash_to_field(msg, 1);
+
+        let rand_curve_elem: G = M::map_to_curve(&rand_field_elems[0]);
+        M::clear_cofactor(&rand_curve_elem)
+    }
+}
@@ -0,0 +1,233 @@
+use ark_ec::hashing::curve_maps::wb::IsogenyMap;
+use ark_ec::short_weierstrass::{Affine, SWCurveConfig};
+use ark_ff::{batch_inversion, Field};
+use ark_std::cfg_iter;
+use ark_std::ops::*;
+use ark_std::vec::Vec;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The rational map of an isogeny from the isogeny curve E' to the origin curve E:
+/// (x', y') = (x_num(x) / x_den(x), y * y_num(x) / y_den(x)),
+/// which is given by the tables of the coefficients in ascending order of the degree,
+/// i.e. k_(1,0), k_(1,1), ... for x_num in RFC 9380, and the denominators keep their leading 1.
+///
+/// The degrees are arbitrary, e.g. the 3-isogeny of secp256k1 or the 11-isogeny of BLS12-381 G1,
+/// see <https://www.rfc-editor.org/rfc/rfc9380.html#section-6.6.3>
+pub struct Isogeny<'a, F: Field> {
+    /// The coefficients of x_num
+    pub x_num: &'a [F],
+
+    /// The coefficients of x_den
+    pub x_den: &'a [F],
+
+    /// The coefficients of y_num
+    pub y_num: &'a [F],
+
+    /// The coefficients of y_den
+    pub y_den: &'a [F],
+}
+
+impl<'a, F: Field> Isogeny<'a, F> {
+    /// Create the isogeny from the coefficient tables
+    pub const fn new(x_num: &'a [F], x_den: &'a [F], y_num: &'a [F], y_den: &'a [F]) -> Self {
+        Self {
+            x_num,
+            x_den,
+            y_num,
+            y_den,
+        }
+    }
+
+    /// Create the isogeny from the `IsogenyMap` of arkworks, whose tables are in the same order
+    pub const fn from_isogeny_map<Domain, Codomain>(map: &IsogenyMap<'a, Domain, Codomain>) -> Self
+    where
+        Domain: SWCurveConfig<BaseField = F>,
+        Codomain: SWCurveConfig<BaseField = F>,
+    {
+        Self::new(
+            map.x_map_numerator,
+            map.x_map_denominator,
+            map.y_map_numerator,
+            map.y_map_denominator,
+        )
+    }
+
+    /// Map the affine point (x, y) of the isogeny curve to the origin curve
+    /// with a single inversion `inv0` for both denominators,
+    /// the points in the kernel of the isogeny, where inv0 returns 0, map to the identity.
+    ///
+    /// It does not branch on (x, y), the caller chooses `inv0`,
+    /// e.g. the constant-time `constant_time::inv0` for the prime fields
+    pub fn map_affine<P: SWCurveConfig<BaseField = F>>(
+        &self,
+        x: &F,
+        y: &F,
+        inv0: impl Fn(&F) -> F,
+    ) -> Affine<P> {
+        let x_num = horner(self.x_num, x);
+        let x_den = horner(self.x_den, x);
+        let y_num = horner(self.y_num, x);
+        let y_den = horner(self.y_den, x);
+
+        let den_inv = inv0(&x_den.mul(&y_den));
+        let x = x_num.mul(&y_den).mul(&den_inv);
+        let y = y_num.mul(y).mul(&x_den).mul(&den_inv);
+
+        Affine {
+            x,
+            y,
+            infinity: den_inv.is_zero(),
+        }
+    }
+
+    /// Map the affine points of the isogeny curve to the origin curve as `map_affine` does,
+    /// sharing one inversion among all the points by Montgomery's trick,
+    /// the points in the kernel of the isogeny map to the identity.
+    ///
+    /// It branches on the zero denominators, so it is not constant time
+    pub fn map_affine_batch<P: SWCurveConfig<BaseField = F>>(
+        &self,
+        points: &[(F, F)],
+    ) -> Vec<Affine<P>> {
+        let evals: Vec<[F; 4]> = cfg_iter!(points)
+            .map(|(x, _)| {
+                [
+                    horner(self.x_num, x),
+                    horner(self.x_den, x),
+                    horner(self.y_num, x),
+                    horner(self.y_den, x),
+                ]
+            })
+            .collect();
+
+        let mut den_invs: Vec<F> = cfg_iter!(evals).map(|e| e[1].mul(&e[3])).collect();
+        batch_inversion(&mut den_invs);
+
+        cfg_iter!(points)
+            .zip(&evals)
+            .zip(&den_invs)
+            .map(|(((_, y), [x_num, x_den, y_num, y_den]), den_inv)| Affine {
+                x: x_num.mul(y_den).mul(den_inv),
+                y: y_num.mul(y).mul(x_den).mul(den_inv),
+                infinity: den_inv.is_zero(),
+            })
+            .collect()
+    }
+
+    /// Map the Jacobian point (x, y, z) of the isogeny curve to the origin curve without inversion,
+    /// the points in the kernel of the isogeny map to the identity, i.e. z = 0
+    pub fn map_jacobian(&self, x: &F, y: &F, z: &F) -> (F, F, F) {
+        let z_2 = z.square();
+        let z_3 = z_2.mul(z);
+
+        // both fractions are brought to the same degree in x / z^2,
+        // and the y-coordinate y / z^3 leaves a z^3 in y_den
+        let x_degree = self.x_num.len().max(self.x_den.len()) - 1;
+        let y_degree = self.y_num.len().max(self.y_den.len()) - 1;
+        let x_num = horner_jacobian(self.x_num, x, &z_2, x_degree);
+        let x_den = horner_jacobian(self.x_den, x, &z_2, x_degree);
+        let y_num = horner_jacobian(self.y_num, x, &z_2, y_degree).mul(y);
+        let y_den = horner_jacobian(self.y_den, x, &z_2, y_degree).mul(&z_3);
+
+        let z = x_den.mul(&y_den);
+        let x = x_num.mul(&y_den).mul(&z);
+        let y = y_num.mul(&x_den).mul(&z).mul(&z);
+        (x, y, z)
+    }
+}
+
+/// Evaluate the polynomial with the coefficients in ascending order of the degree at x
+fn horner<F: Field>(coeffs: &[F], x: &F) -> F {
+    coeffs
+        .iter()
+        .rev()
+        .fold(F::zero(), |acc, coeff| acc.mul(x).add(coeff))
+}
+
+/// Evaluate the polynomial with the coefficients in ascending order of the degree
+/// at the Jacobian x-coordinate x / z^2, multiplied by z^(2 * degree) to clear the denominators,
+/// where `degree` is at least the degree of the polynomial
+fn horner_jacobian<F: Field>(coeffs: &[F], x: &F, z_2: &F, degree: usize) -> F {
+    let mut z_2_pow = z_2.pow([(degree + 1 - coeffs.len()) as u64]);
+    let mut acc = F::zero();
+    for coeff in coeffs.iter().rev() {
+        acc = acc.mul(x).add(coeff.mul(&z_2_pow));
+        z_2_pow.mul_assign(z_2);
+    }
+    acc
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_bls12_381::g1::Config as Bls12381G1Config;
+    use ark_ec::hashing::curve_maps::wb::WBConfig;
+    use ark_ec::short_weierstrass::{Affine, SWCurveConfig};
+    use ark_ff::{Field, PrimeField};
+    use ark_std::ops::*;
+    use ark_std::test_rng;
+    use ark_std::UniformRand;
+
+    use super::Isogeny;
+    use crate::hash_to_curve::constant_time::inv0;
+    use crate::hash_to_curve::simplified_swu::secp256K1_sswu::{Secp256K1SSWUMap, ISOGENY};
+    use crate::hash_to_curve::simplified_swu::SimplifiedSWUMap;
+
+    /// Check that the affine and the Jacobian evaluations agree on the random points of
+    /// the isogeny curve y^2 = x^3 + a * x + b, and that the images are on the origin curve `P`
+    fn check_isogeny<P: SWCurveConfig>(
+        isogeny: &Isogeny<P::BaseField>,
+        a: &P::BaseField,
+        b: &P::BaseField,
+    ) where
+        P::BaseField: PrimeField,
+    {
+        let mut rng = test_rng();
+        let mut points = vec![];
+        let mut count = 0;
+        while count < 20 {
+            let x = P::BaseField::rand(&mut rng);
+            let Some(y) = x.square().add(a).mul(&x).add(b).sqrt() else {
+                continue;
+            };
+            count += 1;
+
+            let point: Affine<P> = isogeny.map_affine(&x, &y, inv0);
+            assert!(point.is_on_curve());
+
+            let z = P::BaseField::rand(&mut rng);
+            let (x_j, y_j, z_j) =
+                isogeny.map_jacobian(&x.mul(z.square()), &y.mul(z.square().mul(&z)), &z);
+            let z_j_inv = z_j.inverse().unwrap();
+            assert_eq!(x_j.mul(z_j_inv.square()), point.x);
+            assert_eq!(y_j.mul(z_j_inv.square().mul(&z_j_inv)), point.y);
+
+            points.push(((x, y), point));
+        }
+
+        // the batch evaluation agrees with the affine one
+        let (inputs, outputs): (Vec<_>, Vec<Affine<P>>) = points.into_iter().unzip();
+        assert_eq!(isogeny.map_affine_batch::<P>(&inputs), outputs);
+    }
+
+    #[test]
+    fn test_isogeny_of_secp256k1() {
+        check_isogeny::<ark_secp256k1::Config>(
+            &ISOGENY,
+            &Secp256K1SSWUMap::A,
+            &Secp256K1SSWUMap::B,
+        );
+    }
+
+    #[test]
+    fn test_isogeny_of_bls12381g1() {
+        type IsogenousCurve = <Bls12381G1Config as WBConfig>::IsogenousCurve;
+
+        check_isogeny::<Bls12381G1Config>(
+            &Isogeny::from_isogeny_map(&Bls12381G1Config::ISOGENY_MAP),
+            &IsogenousCurve::COEFF_A,
+            &IsogenousCurve::COEFF_B,
+        );
+    }
+}
@@ -1,3 +1,21 @@
+/// Module for expanding a message into uniformly random bytes
+pub mod expander;
+
+/// Module for hashing a message to field elements
+pub mod field_hasher;
+
+/// Module for the constant-time building blocks of the maps
+pub mod constant_time;
+
+/// Module for the isogeny maps evaluated from the coefficient tables
+pub mod isogeny;
+
+/// Module for selecting the constant Z of the maps
+pub mod z_selection;
+
+/// Module for the common interface of hashing to curve over all the maps
+pub mod hasher;
+
 /// Module for SW map
 pub mod sw_map;
 
@@ -7,5 +25,8 @@ pub mod simplified_swu;
 /// Module for optimized SWU
 pub mod optimized_swu;
 
+/// Module for Elligator 2
+pub mod elligator2;
+
 #[cfg(test)]
 mod tests;
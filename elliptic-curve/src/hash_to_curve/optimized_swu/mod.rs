@@ -1,123 +1,214 @@
-use ark_ec::hashing::curve_maps::swu::parity;
+use ark_ec::hashing::map_to_curve_hasher::MapToCurve;
+use ark_ec::hashing::HashToCurveError;
+use ark_ec::short_weierstrass::Affine;
 use ark_ec::short_weierstrass::Projective;
 use ark_ec::short_weierstrass::SWCurveConfig;
+use ark_ec::CurveGroup;
 use ark_ec::Group;
-use ark_ff::field_hashers::{DefaultFieldHasher, HashToField};
+use ark_ff::field_hashers::HashToField;
 use ark_ff::Field;
-use ark_ff::One;
-use sha2::digest::DynDigest;
-use std::ops::*;
+use ark_ff::Zero;
+use ark_ff::{FftField, PrimeField};
+use ark_std::marker::PhantomData;
+use ark_std::ops::*;
+use ark_std::string::ToString;
+
+use super::constant_time::{sswu, sswu_3mod4, ConstantTime};
+use super::hasher::HashToCurve;
 
 /// Module for the secp256K1 instance of the Optimized SWU map
 #[allow(non_snake_case)]
 pub mod secp256K1_oswu;
 
+/// Module for the secp256R1 (P-256) instance of the Optimized SWU map
+#[allow(non_snake_case)]
+pub mod secp256R1_oswu;
+
+/// Module for the secp384R1 (P-384) instance of the Optimized SWU map
+#[allow(non_snake_case)]
+pub mod secp384R1_oswu;
+
+/// Module for the secp521R1 (P-521) instance of the Optimized SWU map
+#[allow(non_snake_case)]
+pub mod secp521R1_oswu;
+
+/// Module for the BLS12-381 G1 instance of the Optimized SWU map
+#[allow(non_snake_case)]
+pub mod bls12381G1_oswu;
+
+/// Module for the Pallas instance of the Optimized SWU map
+pub mod pallas_oswu;
+
+/// Module for the Vesta instance of the Optimized SWU map
+pub mod vesta_oswu;
+
 /// Trait for implementing a Optimized Shallue-van de Woestijne-Ulas method that is effective for
 /// Weierstrass equation y^2 = x^3 + A * x +B where A != 0 and B != 0.
 ///
 /// see <https://eprint.iacr.org/2019/403.pdf> [section 4.2]
-pub trait OptimizedSWUMap<P: SWCurveConfig> {
+pub trait OptimizedSWUMap<P: SWCurveConfig>
+where
+    P::BaseField: PrimeField,
+{
     /// The constant z
     const Z: P::BaseField;
 
-    /// The constant c1
-    const C1: Option<P::BaseField>;
+    /// The constant c2
+    const C2: Option<P::BaseField>;
 
-    /// The parameter A of isogeny curve
-    const A: P::BaseField;
+    /// The parameter A of isogeny curve,
+    /// which is the parameter A of the curve itself if no isogeny is needed (A != 0 and B != 0)
+    const A: P::BaseField = P::COEFF_A;
 
-    /// The parameter B of isogeny curve
-    const B: P::BaseField;
+    /// The parameter B of isogeny curve,
+    /// which is the parameter B of the curve itself if no isogeny is needed (A != 0 and B != 0)
+    const B: P::BaseField = P::COEFF_B;
 
-    /// Domain separation
+    /// The default domain separation tag,
+    /// applications should separate their own domains by `hash_with_dst` or a `Hasher`
     const DST: &'static [u8];
 
-    /// Mapping an arbitrary field element to a point on the elliptic curve,
+    /// The hash_to_field function of the suite, e.g. `XmdFieldHasher<Sha256>`,
+    /// which `HashToCurve::hash_to_curve` and `HashToCurve::encode_to_curve` use
+    type FieldHasher: HashToField<P::BaseField>;
+
+    /// The effective cofactor h_eff of RFC 9380 in little-endian limbs,
+    /// which is the cofactor of the curve by default
+    const H_EFF: &'static [u64] = P::COFACTOR;
+
+    /// Mapping an arbitrary field element to a point on the elliptic curve in Jacobian coordinates,
     /// This step matching step 2 and step 3
+    ///
+    /// It is straight-line and free of inversions. For p = 3 mod 4 the square root and the quadratic residuosity
+    /// come from a single exponentiation (sqrt_ratio_3mod4), otherwise the generic sqrt_ratio is used.
+    /// The exceptional inputs with Z^2 * u^4 + Z * u^2 = 0 (e.g. u = 0) are handled as `inv0` does,
+    /// see <https://www.rfc-editor.org/rfc/rfc9380.html#appendix-G.2.3>
     fn map_to_curve(u: &P::BaseField) -> Projective<P> {
-        let u2_mul_z: P::BaseField = u.square().mul(&Self::Z);
-        let u4_mul_z2: P::BaseField = u2_mul_z.square();
-        let x1_den: P::BaseField = u2_mul_z.add(&u4_mul_z2);
-        let x1_num: P::BaseField = x1_den.add(&P::BaseField::one());
-        let x1_num: P::BaseField = x1_num.mul(&Self::c1());
-
-        let num_2: P::BaseField = x1_num.square();
-        let den_2: P::BaseField = x1_den.square();
-        let den_3: P::BaseField = den_2.mul(&x1_den);
-
-        let x1 = x1_num.mul(&x1_den);
-        let y1_num: P::BaseField = num_2
-            .add(Self::A.mul(&den_2))
-            .mul(&x1_num)
-            .add(Self::B.mul(&den_3));
-        let y1_square: P::BaseField = y1_num.div(&den_3);
-        if y1_square.legendre().is_qr() {
-            let y1 = y1_square.sqrt().unwrap();
-            let y1 = if parity(&y1) != parity(u) { -y1 } else { y1 };
-            let y1 = y1.mul(&den_3);
-            let (x, y, z) = Self::isogeny_map(&x1, &y1, &x1_den);
-            let point = Projective::<P>::new_unchecked(x, y, z);
-            return point;
+        let (x_num, x_den, y) = if P::BaseField::TWO_ADICITY == 1 {
+            sswu_3mod4(u, &Self::A, &Self::B, &Self::Z, &Self::c2())
+        } else {
+            sswu(u, &Self::A, &Self::B, &Self::Z)
+        };
+
+        // the Jacobian coordinates of (x_num / x_den, y) are (x_num * x_den, y * x_den^3, x_den)
+        let x: P::BaseField = x_num.mul(&x_den);
+        let y: P::BaseField = y.mul(&x_den.square()).mul(&x_den);
+
+        let (x, y, z) = Self::isogeny_map(&x, &y, &x_den);
+        Projective::<P>::new_unchecked(x, y, z)
+    }
+
+    /// The constant-time version of `map_to_curve`,
+    /// which is the same map since `map_to_curve` is already straight-line
+    fn map_to_curve_ct(u: &P::BaseField) -> Projective<P> {
+        Self::map_to_curve(u)
+    }
+
+    /// The isogeny map from isogeny curve to origin curve in Jacobian coordinates,
+    /// the points in the kernel of the isogeny are mapped to the identity, i.e. z = 0
+    ///
+    /// The default is the identity map, for the curves where SSWU applies directly
+    fn isogeny_map(
+        x: &P::BaseField,
+        y: &P::BaseField,
+        z: &P::BaseField,
+    ) -> (P::BaseField, P::BaseField, P::BaseField) {
+        (*x, *y, *z)
+    }
+
+    /// The constant c2 equals ：
+    /// c2 = sqrt(-Z^3), which is only used for p = 3 mod 4
+    fn c2() -> P::BaseField {
+        if let Some(c2) = Self::C2 {
+            c2
+        } else {
+            let z3_neg = Self::Z.square().mul(&Self::Z).neg();
+            z3_neg.sqrt().unwrap()
         }
+    }
 
-        let x2 = x1.mul(&u2_mul_z);
-        let y1_square_z = y1_square.mul(&Self::Z);
-        let y2 = y1_square_z.sqrt().unwrap().mul(&u2_mul_z).mul(u);
-        let y2 = if parity(&y2) != parity(u) { -y2 } else { y2 };
-        let y2 = y2.mul(&den_3);
+    /// Clearing the cofactor of a point on the curve, this step matching step 5 of hashing to curve
+    ///
+    /// The default multiplies by `H_EFF`, the instances on the curves with an endomorphism
+    /// override it with a faster method, e.g. the psi endomorphism of BLS12-381 G2
+    fn clear_cofactor(point: &Projective<P>) -> Projective<P> {
+        point.mul_bigint(Self::H_EFF)
+    }
+}
 
-        let (x, y, z) = Self::isogeny_map(&x2, &y2, &x1_den);
-        let point = Projective::<P>::new_unchecked(x, y, z);
+/// Hashing to curve with the Optimized SWU map `M`, e.g. `OptimizedSWUHashToCurve<Secp256K1OSWUMap>`,
+/// which is the `HashToCurve` of the Optimized SWU map family
+pub struct OptimizedSWUHashToCurve<M>(PhantomData<M>);
+
+impl<P: SWCurveConfig, M: OptimizedSWUMap<P>> HashToCurve<Projective<P>>
+    for OptimizedSWUHashToCurve<M>
+where
+    P::BaseField: PrimeField,
+{
+    const DST: &'static [u8] = M::DST;
+    type FieldHasher = M::FieldHasher;
+
+    fn map_to_curve(u: &P::BaseField) -> Projective<P> {
+        M::map_to_curve(u)
+    }
 
-        return point;
+    fn clear_cofactor(point: &Projective<P>) -> Projective<P> {
+        M::clear_cofactor(point)
     }
+}
 
-    /// Mapping an arbitrary message to a field element,
-    /// This step matching step 1
-    fn hash_to_field<H: Default + DynDigest + Clone>(msg: &[u8], dst: &[u8]) -> Vec<P::BaseField> {
-        let field_hasher = <DefaultFieldHasher<H> as HashToField<P::BaseField>>::new(dst);
+/// The `MapToCurve` of arkworks, so that the Optimized SWU maps can be plugged into
+/// `MapToCurveBasedHasher` and the libraries built on arkworks
+impl<P: SWCurveConfig, M: OptimizedSWUMap<P>> MapToCurve<Projective<P>>
+    for OptimizedSWUHashToCurve<M>
+where
+    P::BaseField: PrimeField,
+{
+    fn new() -> Result<Self, HashToCurveError> {
+        if M::Z.legendre().is_qr() {
+            return Err(HashToCurveError::MapToCurveError(
+                "Z should be a quadratic non-residue for the Optimized SWU map".to_string(),
+            ));
+        }
+        if M::A.is_zero() || M::B.is_zero() {
+            return Err(HashToCurveError::MapToCurveError(
+                "A * B should be nonzero for the Optimized SWU map".to_string(),
+            ));
+        }
 
-        let field_elems: Vec<P::BaseField> = field_hasher.hash_to_field(msg, 2);
+        Ok(Self(PhantomData))
+    }
 
-        field_elems
+    fn map_to_curve(&self, point: P::BaseField) -> Result<Affine<P>, HashToCurveError> {
+        Ok(M::map_to_curve(&point).into_affine())
     }
+}
 
-    /// Perform hashing to curve
-    ///
-    /// To implement hashing to curve, the following steps are usually required:
-    /// step 1 : u = hash_to_field(msg, 2)
-    /// step 2 : Q0 = map_to_curve(u[0])
-    /// step 3 : Q1 = map_to_curve(u[1])
-    /// step 4 : R = Q0 + Q1
-    /// step 5 : P = clear_cofactor(R)
-    /// step 6 : return P
-    fn hash<H: Default + DynDigest + Clone>(msg: &[u8]) -> Projective<P> {
-        let rand_field_elems: Vec<P::BaseField> = Self::hash_to_field::<H>(msg, Self::DST);
-
-        let rand_curve_elem_0 = Self::map_to_curve(&rand_field_elems[0]);
-        let rand_curve_elem_1 = Self::map_to_curve(&rand_field_elems[1]);
-
-        let rand_curve_elem: Projective<P> = rand_curve_elem_0.add(&rand_curve_elem_1);
-        let rand_subgroup_elem = rand_curve_elem.mul_bigint(P::COFACTOR);
-
-        rand_subgroup_elem
+impl<P: SWCurveConfig, M: OptimizedSWUMap<P>> OptimizedSWUMap<P> for ConstantTime<M>
+where
+    P::BaseField: PrimeField,
+{
+    const Z: P::BaseField = M::Z;
+    const C2: Option<P::BaseField> = M::C2;
+    const A: P::BaseField = M::A;
+    const B: P::BaseField = M::B;
+    const DST: &'static [u8] = M::DST;
+    type FieldHasher = M::FieldHasher;
+    const H_EFF: &'static [u64] = M::H_EFF;
+
+    fn map_to_curve(u: &P::BaseField) -> Projective<P> {
+        M::map_to_curve_ct(u)
     }
 
-    /// The isogeny map from isogeny curve to origin curve
     fn isogeny_map(
         x: &P::BaseField,
         y: &P::BaseField,
         z: &P::BaseField,
-    ) -> (P::BaseField, P::BaseField, P::BaseField);
+    ) -> (P::BaseField, P::BaseField, P::BaseField) {
+        M::isogeny_map(x, y, z)
+    }
 
-    /// The constant c1 equals ：
-    /// c1 = - B/A
-    fn c1() -> P::BaseField {
-        if let Some(c1) = Self::C1 {
-            c1
-        } else {
-            let b_neg = Self::B.neg();
-            b_neg.div(&Self::A)
-        }
+    fn clear_cofactor(point: &Projective<P>) -> Projective<P> {
+        M::clear_cofactor(point)
     }
 }
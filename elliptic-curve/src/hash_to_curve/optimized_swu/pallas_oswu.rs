@@ -0,0 +1,79 @@
+use ark_ff::MontFp;
+use ark_pallas::{Fq, PallasConfig};
+use blake2::Blake2b512;
+
+use crate::hash_to_curve::field_hasher::XmdFieldHasher;
+use crate::hash_to_curve::isogeny::Isogeny;
+
+use super::OptimizedSWUMap;
+
+/// The Optimized SWU map for Pallas: y^2 = x^3 + 5, which maps to the 3-isogenous curve
+/// iso-Pallas: y^2 = x^3 + A' * x + 1265 and then to Pallas by the 3-isogeny.
+///
+/// The field has 2-adicity 32, so the square roots are taken by the constant-time `sqrt_ratio`
+/// rather than the shortcut for p = 3 mod 4.
+pub struct PallasOSWUMap;
+
+/// The 3-isogeny map from iso-Pallas to Pallas, the coefficients are in ascending order of the degree,
+/// see the `pasta_curves` crate of Zcash
+const X_MAP_NUMERATOR: [Fq; 4] = [
+    MontFp!("12865787693035132824841220556520878650383580658640693651535411895266652280192"),
+    MontFp!("10492611921771203378452795982353351666191589197598957448093274638589204800759"),
+    MontFp!("-4958326160178856490552523507003761961853240923730573943603540848527702426763"),
+    MontFp!("6432893846517566412420610278260439325191790329320346825767705947633326140075"),
+];
+
+const X_MAP_DENOMINATOR: [Fq; 3] = [
+    MontFp!("-6179701205467997340701970998179274646457656484243756061028352401591146682877"),
+    MontFp!("13271109177048389296812780941310096270046944650307955939477485891950613419807"),
+    MontFp!("1"),
+];
+
+const Y_MAP_NUMERATOR: [Fq; 4] = [
+    MontFp!("1072148974419594402070101713043406554198631721553391137627950991272221023311"),
+    MontFp!("-124452699277652753530076400933679841781581584725903644930895344306205904333"),
+    MontFp!("11994848074575096182670111372584107500754907779105493386175567957911132601787"),
+    MontFp!("11793638718615538422771118843477472096184948937087302513907460903994431256804"),
+];
+
+const Y_MAP_DENOMINATOR: [Fq; 4] = [
+    MontFp!("-540"),
+    MontFp!("10408918692925056833786833257634153023990087029210292532869619559576527581706"),
+    MontFp!("5432652610908059517272798285879155923388888734491153551238890455750936314542"),
+    MontFp!("1"),
+];
+
+/// The 3-isogeny from iso-Pallas to Pallas evaluated from the tables above
+const ISOGENY: Isogeny<'static, Fq> = Isogeny::new(
+    &X_MAP_NUMERATOR,
+    &X_MAP_DENOMINATOR,
+    &Y_MAP_NUMERATOR,
+    &Y_MAP_DENOMINATOR,
+);
+
+impl OptimizedSWUMap<PallasConfig> for PallasOSWUMap {
+    const Z: Fq = MontFp!("-13");
+
+    /// c2 is only used for p = 3 mod 4
+    const C2: Option<Fq> = None;
+
+    const A: Fq =
+        MontFp!("10949663248450308183708987909873589833737836120165333298109615750520499732811");
+
+    const B: Fq = MontFp!("1265");
+
+    const DST: &'static [u8] = b"pallas_oswu";
+
+    /// The hash_to_field function of the suites `pallas_XMD:BLAKE2b_SSWU_RO_`
+    /// and `pallas_XMD:BLAKE2b_SSWU_NU_` of the `pasta_curves` crate
+    ///
+    /// The tag of the application is e.g. `z.cash:test-pallas_XMD:BLAKE2b_SSWU_RO_`
+    /// for the domain prefix `z.cash:test`.
+    ///
+    /// Each field element is hashed from 64 bytes (L = 64).
+    type FieldHasher = XmdFieldHasher<Blake2b512, 256>;
+
+    fn isogeny_map(isogeny_x: &Fq, isogeny_y: &Fq, isogeny_z: &Fq) -> (Fq, Fq, Fq) {
+        ISOGENY.map_jacobian(isogeny_x, isogeny_y, isogeny_z)
+    }
+}
@@ -0,0 +1,25 @@
+use ark_ff::MontFp;
+use ark_secp256r1::Fq;
+use sha2::Sha256;
+
+use crate::hash_to_curve::field_hasher::XmdFieldHasher;
+
+use super::OptimizedSWUMap;
+
+/// The Optimized SWU map for P-256, which applies directly to the curve since A != 0 and B != 0,
+/// so the parameters A, B and the isogeny map take the defaults.
+pub struct Secp256R1OSWUMap;
+
+impl OptimizedSWUMap<ark_secp256r1::Config> for Secp256R1OSWUMap {
+    const Z: Fq = MontFp!("-10");
+
+    const C2: Option<Fq> = Some(MontFp!(
+        "61181595916461500887819182775203103403569161913793237367988468591075761958142"
+    ));
+
+    const DST: &'static [u8] = b"secp256r1_oswu";
+
+    /// The hash_to_field function of the suites `P256_XMD:SHA-256_SSWU_RO_`
+    /// and `P256_XMD:SHA-256_SSWU_NU_` of RFC 9380
+    type FieldHasher = XmdFieldHasher<Sha256>;
+}
@@ -0,0 +1,25 @@
+use ark_ff::MontFp;
+use ark_secp384r1::{Config, Fq};
+use sha2::Sha384;
+
+use crate::hash_to_curve::field_hasher::XmdFieldHasher;
+
+use super::OptimizedSWUMap;
+
+/// The Optimized SWU map for P-384, which applies directly to the curve since A != 0 and B != 0,
+/// so the parameters A, B and the isogeny map take the defaults.
+pub struct Secp384R1OSWUMap;
+
+impl OptimizedSWUMap<Config> for Secp384R1OSWUMap {
+    const Z: Fq = MontFp!("-12");
+
+    const C2: Option<Fq> = Some(MontFp!(
+        "245581924330355933059354953436454308283684637692875694451750329610872227127112066054762383127358415667364631269478"
+    ));
+
+    const DST: &'static [u8] = b"secp384r1_oswu";
+
+    /// The hash_to_field function of the suites `P384_XMD:SHA-384_SSWU_RO_`
+    /// and `P384_XMD:SHA-384_SSWU_NU_` of RFC 9380
+    type FieldHasher = XmdFieldHasher<Sha384, 192>;
+}
@@ -0,0 +1,23 @@
+use ark_ff::MontFp;
+use sha2::Sha512;
+
+use crate::curves::secp521r1::{Config, Fq};
+use crate::hash_to_curve::field_hasher::XmdFieldHasher;
+
+use super::OptimizedSWUMap;
+
+/// The Optimized SWU map for P-521, which applies directly to the curve since A != 0 and B != 0,
+/// so the parameters A, B and the isogeny map take the defaults.
+pub struct Secp521R1OSWUMap;
+
+impl OptimizedSWUMap<Config> for Secp521R1OSWUMap {
+    const Z: Fq = MontFp!("-4");
+
+    const C2: Option<Fq> = Some(MontFp!("8"));
+
+    const DST: &'static [u8] = b"secp521r1_oswu";
+
+    /// The hash_to_field function of the suites `P521_XMD:SHA-512_SSWU_RO_`
+    /// and `P521_XMD:SHA-512_SSWU_NU_` of RFC 9380
+    type FieldHasher = XmdFieldHasher<Sha512, 256>;
+}
@@ -0,0 +1,79 @@
+use ark_ff::MontFp;
+use ark_vesta::{Fq, VestaConfig};
+use blake2::Blake2b512;
+
+use crate::hash_to_curve::field_hasher::XmdFieldHasher;
+use crate::hash_to_curve::isogeny::Isogeny;
+
+use super::OptimizedSWUMap;
+
+/// The Optimized SWU map for Vesta: y^2 = x^3 + 5, which maps to the 3-isogenous curve
+/// iso-Vesta: y^2 = x^3 + A' * x + 1265 and then to Vesta by the 3-isogeny.
+///
+/// The field has 2-adicity 32, so the square roots are taken by the constant-time `sqrt_ratio`
+/// rather than the shortcut for p = 3 mod 4.
+pub struct VestaOSWUMap;
+
+/// The 3-isogeny map from iso-Vesta to Vesta, the coefficients are in ascending order of the degree,
+/// see the `pasta_curves` crate of Zcash
+const X_MAP_NUMERATOR: [Fq; 4] = [
+    MontFp!("-6432893846517566412420610278260439325191790329320366084373276166309636210646"),
+    MontFp!("11064082577423419940183149293632076317553812518550871517841037420579891210813"),
+    MontFp!("13377367003779316331268047403600734872799183885837485433911493934102207511749"),
+    MontFp!("-3216446923258783206210305139130219662595895164660183042186638083154818105344"),
+];
+
+const X_MAP_DENOMINATOR: [Fq; 3] = [
+    MontFp!("9250006497141849826017568406346290940322373181457057184910582871723433210981"),
+    MontFp!("4604213796697651557841441623718706001740429044770779386484474413346415813353"),
+    MontFp!("1"),
+];
+
+const Y_MAP_NUMERATOR: [Fq; 4] = [
+    MontFp!("13937936667454727226911322269564285204582212380194126516142098360337545123123"),
+    MontFp!("11620280474556824258112134491145636201000922752744881519070727793732904824884"),
+    MontFp!("-7785327652774866262312349424285621045281936298052080972884124407145577718174"),
+    MontFp!("8577191795356755216560813704347252433589053772427154779164368221746181614251"),
+];
+
+const Y_MAP_DENOMINATOR: [Fq; 4] = [
+    MontFp!("-540"),
+    MontFp!("-1198002817903499377840041033133104142395936937570475824947994133223063315154"),
+    MontFp!("-7567690459618047091184210690507929479070884673814654610113159754177057754019"),
+    MontFp!("1"),
+];
+
+/// The 3-isogeny from iso-Vesta to Vesta evaluated from the tables above
+const ISOGENY: Isogeny<'static, Fq> = Isogeny::new(
+    &X_MAP_NUMERATOR,
+    &X_MAP_DENOMINATOR,
+    &Y_MAP_NUMERATOR,
+    &Y_MAP_DENOMINATOR,
+);
+
+impl OptimizedSWUMap<VestaConfig> for VestaOSWUMap {
+    const Z: Fq = MontFp!("-13");
+
+    /// c2 is only used for p = 3 mod 4
+    const C2: Option<Fq> = None;
+
+    const A: Fq =
+        MontFp!("17413348858408915339762682399132325137863850198379221683097628341577494210225");
+
+    const B: Fq = MontFp!("1265");
+
+    const DST: &'static [u8] = b"vesta_oswu";
+
+    /// The hash_to_field function of the suites `vesta_XMD:BLAKE2b_SSWU_RO_`
+    /// and `vesta_XMD:BLAKE2b_SSWU_NU_` of the `pasta_curves` crate
+    ///
+    /// The tag of the application is e.g. `z.cash:test-vesta_XMD:BLAKE2b_SSWU_RO_`
+    /// for the domain prefix `z.cash:test`.
+    ///
+    /// Each field element is hashed from 64 bytes (L = 64).
+    type FieldHasher = XmdFieldHasher<Blake2b512, 256>;
+
+    fn isogeny_map(isogeny_x: &Fq, isogeny_y: &Fq, isogeny_z: &Fq) -> (Fq, Fq, Fq) {
+        ISOGENY.map_jacobian(isogeny_x, isogeny_y, isogeny_z)
+    }
+}
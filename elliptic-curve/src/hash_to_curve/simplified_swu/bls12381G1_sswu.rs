@@ -0,0 +1,51 @@
+use ark_bls12_381::g1::Config;
+use ark_bls12_381::Fq;
+use ark_ec::hashing::curve_maps::wb::WBConfig;
+use ark_ec::short_weierstrass::{Affine, SWCurveConfig};
+use ark_ff::MontFp;
+use ark_std::vec::Vec;
+use sha2::Sha256;
+
+use crate::hash_to_curve::constant_time::inv0;
+use crate::hash_to_curve::field_hasher::XmdFieldHasher;
+use crate::hash_to_curve::isogeny::Isogeny;
+
+use super::SimplifiedSWUMap;
+
+/// The Simplified SWU map for BLS12-381 G1, which maps to the 11-isogenous curve
+/// E': y^2 = x^3 + A' * x + B' and then to G1 by the 11-isogeny.
+pub struct Bls12381G1SSWUMap;
+
+/// The isogenous curve E' of G1
+type IsogenousCurve = <Config as WBConfig>::IsogenousCurve;
+
+/// The 11-isogeny map from E' to G1, the coefficients are in ascending order of the degree,
+/// see <https://www.rfc-editor.org/rfc/rfc9380.html#appendix-E.2>
+const ISOGENY: Isogeny<'static, Fq> = Isogeny::from_isogeny_map(&Config::ISOGENY_MAP);
+
+impl SimplifiedSWUMap<Config> for Bls12381G1SSWUMap {
+    const Z: Fq = MontFp!("11");
+
+    const C1: Option<Fq> = Some(MontFp!("1165829013300031051498189320085913366300917435352691079268722832469236884678791583237077507172460989948189414825084"));
+
+    const A: Fq = IsogenousCurve::COEFF_A;
+
+    const B: Fq = IsogenousCurve::COEFF_B;
+
+    const DST: &'static [u8] = b"bls12381g1_sswu";
+
+    /// The hash_to_field function of the suites `BLS12381G1_XMD:SHA-256_SSWU_RO_`
+    /// and `BLS12381G1_XMD:SHA-256_SSWU_NU_` of RFC 9380
+    type FieldHasher = XmdFieldHasher<Sha256>;
+
+    /// h_eff = 1 - x = 0xd201000000010001 for the BLS parameter x, see section 8.8.1 of RFC 9380
+    const H_EFF: &'static [u64] = &[0xd201000000010001];
+
+    fn isogeny_map(isogeny_x: &Fq, isogeny_y: &Fq) -> Affine<Config> {
+        ISOGENY.map_affine(isogeny_x, isogeny_y, inv0)
+    }
+
+    fn isogeny_map_batch(points: &[(Fq, Fq)]) -> Vec<Affine<Config>> {
+        ISOGENY.map_affine_batch(points)
+    }
+}
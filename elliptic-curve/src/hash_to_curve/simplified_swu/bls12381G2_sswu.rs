@@ -0,0 +1,92 @@
+use ark_bls12_381::g2::Config;
+use ark_bls12_381::Fq2;
+use ark_ec::hashing::curve_maps::wb::WBConfig;
+use ark_ec::short_weierstrass::{Affine, Projective, SWCurveConfig};
+use ark_ec::CurveGroup;
+use ark_ff::MontFp;
+use ark_ff::{Field, Zero};
+use ark_std::vec::Vec;
+use sha2::Sha256;
+
+use crate::hash_to_curve::field_hasher::XmdFieldHasher;
+use crate::hash_to_curve::isogeny::Isogeny;
+
+use super::SimplifiedSWUMap;
+
+/// The Simplified SWU map for BLS12-381 G2 over Fp2 = Fp[I] / (I^2 + 1), which maps to the 3-isogenous curve
+/// E': y^2 = x^3 + 240 * I * x + 1012 * (1 + I) and then to G2 by the 3-isogeny.
+///
+/// The map takes the square roots and the sign sgn0 of Fp2,
+/// there is no `OptimizedSWUMap` instance since the straight-line maps are over prime fields.
+///
+/// For the same reason `ConstantTime<Bls12381G2SSWUMap>` is not a map, so it does not compile:
+///
+/// ```compile_fail
+/// use elliptic_curve::hash_to_curve::constant_time::ConstantTime;
+/// use elliptic_curve::hash_to_curve::hasher::HashToCurve;
+/// use elliptic_curve::hash_to_curve::simplified_swu::bls12381G2_sswu::Bls12381G2SSWUMap;
+/// use elliptic_curve::hash_to_curve::simplified_swu::SimplifiedSWUHashToCurve;
+///
+/// SimplifiedSWUHashToCurve::<ConstantTime<Bls12381G2SSWUMap>>::hash_to_curve(b"msg", b"dst");
+/// ```
+pub struct Bls12381G2SSWUMap;
+
+/// The isogenous curve E' of G2
+type IsogenousCurve = <Config as WBConfig>::IsogenousCurve;
+
+/// The 3-isogeny map from E' to G2, the coefficients are in ascending order of the degree,
+/// see <https://www.rfc-editor.org/rfc/rfc9380.html#appendix-E.3>
+const ISOGENY: Isogeny<'static, Fq2> = Isogeny::from_isogeny_map(&Config::ISOGENY_MAP);
+
+impl SimplifiedSWUMap<Config> for Bls12381G2SSWUMap {
+    /// Z = -(2 + I)
+    const Z: Fq2 = Fq2::new(MontFp!("-2"), MontFp!("-1"));
+
+    /// c1 = -B' / A' = 1012 / 240 * (-1 + I)
+    const C1: Option<Fq2> = Some(Fq2::new(
+        MontFp!("1267429692486861341248966778149702982909679559647352497021818409772610022655431990406851082557521626945333186310595"),
+        MontFp!("2734979862734806052168823047586201173647203260291655388310239726351421627835405874035836546571494037092561086249192"),
+    ));
+
+    const A: Fq2 = IsogenousCurve::COEFF_A;
+
+    const B: Fq2 = IsogenousCurve::COEFF_B;
+
+    const DST: &'static [u8] = b"bls12381g2_sswu";
+
+    /// The hash_to_field function of the suites `BLS12381G2_XMD:SHA-256_SSWU_RO_`
+    /// and `BLS12381G2_XMD:SHA-256_SSWU_NU_` of RFC 9380
+    ///
+    /// Each element of Fp2 is hashed from 2 * 64 bytes (m = 2, L = 64).
+    type FieldHasher = XmdFieldHasher<Sha256>;
+
+    /// h_eff of G2, see section 8.8.2 of RFC 9380
+    const H_EFF: &'static [u64] = &[
+        0xe8020005aaa95551,
+        0x59894c0adebbf6b4,
+        0xe954cbc06689f6a3,
+        0x2ec0ec69d7477c1a,
+        0x6d82bf015d1212b0,
+        0x329c2f178731db95,
+        0x9986ff031508ffe1,
+        0x88e2a8e9145ad768,
+        0x584c6a0ea91b3528,
+        0x0bc69f08f2ee75b3,
+    ];
+
+    fn isogeny_map(isogeny_x: &Fq2, isogeny_y: &Fq2) -> Affine<Config> {
+        ISOGENY.map_affine(isogeny_x, isogeny_y, |x| {
+            x.inverse().unwrap_or_else(Fq2::zero)
+        })
+    }
+
+    fn isogeny_map_batch(points: &[(Fq2, Fq2)]) -> Vec<Affine<Config>> {
+        ISOGENY.map_affine_batch(points)
+    }
+
+    /// The multiplication by h_eff with the psi endomorphism of Budroni-Pintore,
+    /// i.e. `SWCurveConfig::clear_cofactor` of arkworks, see appendix G.3 of RFC 9380
+    fn clear_cofactor(point: &Projective<Config>) -> Projective<Config> {
+        Config::clear_cofactor(&point.into_affine()).into()
+    }
+}
@@ -1,18 +1,51 @@
 use ark_ec::hashing::curve_maps::swu::parity;
+use ark_ec::hashing::map_to_curve_hasher::MapToCurve;
+use ark_ec::hashing::HashToCurveError;
 use ark_ec::short_weierstrass::Affine;
 use ark_ec::short_weierstrass::Projective;
 use ark_ec::short_weierstrass::SWCurveConfig;
 use ark_ec::Group;
-use ark_ff::field_hashers::{DefaultFieldHasher, HashToField};
+use ark_ff::batch_inversion;
+use ark_ff::field_hashers::HashToField;
 use ark_ff::Field;
 use ark_ff::One;
-use sha2::digest::DynDigest;
-use std::ops::*;
+use ark_ff::PrimeField;
+use ark_ff::Zero;
+use ark_std::cfg_iter;
+use ark_std::marker::PhantomData;
+use ark_std::ops::*;
+use ark_std::{string::ToString, vec::Vec};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use super::constant_time::{inv0, sswu, ConstantTime};
+use super::hasher::HashToCurve;
 
 /// Module for the secp256K1 instance of the Simplified SWU map"
 #[allow(non_snake_case)]
 pub mod secp256K1_sswu;
 
+/// Module for the secp256R1 (P-256) instance of the Simplified SWU map
+#[allow(non_snake_case)]
+pub mod secp256R1_sswu;
+
+/// Module for the secp384R1 (P-384) instance of the Simplified SWU map
+#[allow(non_snake_case)]
+pub mod secp384R1_sswu;
+
+/// Module for the secp521R1 (P-521) instance of the Simplified SWU map
+#[allow(non_snake_case)]
+pub mod secp521R1_sswu;
+
+/// Module for the BLS12-381 G1 instance of the Simplified SWU map
+#[allow(non_snake_case)]
+pub mod bls12381G1_sswu;
+
+/// Module for the BLS12-381 G2 instance of the Simplified SWU map
+#[allow(non_snake_case)]
+pub mod bls12381G2_sswu;
+
 /// Trait for implementing a Simplified Shallue-van de Woestijne-Ulas method that is effective for
 /// Weierstrass equation y^2 = x^3 + A * x +B where A != 0 and B != 0.
 ///
@@ -24,74 +57,85 @@ pub trait SimplifiedSWUMap<P: SWCurveConfig> {
     /// The constant c1
     const C1: Option<P::BaseField>;
 
-    /// The parameter A of isogeny curve
-    const A: P::BaseField;
+    /// The parameter A of isogeny curve,
+    /// which is the parameter A of the curve itself if no isogeny is needed (A != 0 and B != 0)
+    const A: P::BaseField = P::COEFF_A;
 
-    /// The parameter B of isogeny curve
-    const B: P::BaseField;
+    /// The parameter B of isogeny curve,
+    /// which is the parameter B of the curve itself if no isogeny is needed (A != 0 and B != 0)
+    const B: P::BaseField = P::COEFF_B;
 
-    /// Domain separation
+    /// The default domain separation tag,
+    /// applications should separate their own domains by `hash_with_dst` or a `Hasher`
     const DST: &'static [u8];
 
+    /// The hash_to_field function of the suite, e.g. `XmdFieldHasher<Sha256>`,
+    /// which `HashToCurve::hash_to_curve` and `HashToCurve::encode_to_curve` use
+    type FieldHasher: HashToField<P::BaseField>;
+
+    /// The effective cofactor h_eff of RFC 9380 in little-endian limbs,
+    /// which is the cofactor of the curve by default
+    const H_EFF: &'static [u64] = P::COFACTOR;
+
     /// Mapping an arbitrary field element to a point on the elliptic curve,
     /// This step matching step 2 and step 3
+    ///
+    /// The exceptional inputs with Z^2 * u^4 + Z * u^2 = 0 (e.g. u = 0) are handled by `inv0`,
+    /// see <https://www.rfc-editor.org/rfc/rfc9380.html#section-6.6.2>
     fn map_to_curve(u: &P::BaseField) -> Affine<P> {
-        let u2_mul_z: P::BaseField = u.square().mul(&Self::Z);
-        let u4_mul_z2: P::BaseField = u2_mul_z.square();
-        let tv1: P::BaseField = u2_mul_z.add(&u4_mul_z2).inverse().unwrap();
-
-        let x1: P::BaseField = Self::c1().mul(&tv1.add(&P::BaseField::one()));
-        let gx1: P::BaseField = x1.square().add(&Self::A);
-        let gx1: P::BaseField = gx1.mul(&x1).add(&Self::B);
-        if gx1.legendre().is_qr() {
-            let (x, y) = Self::isogeny_map(&x1, &gx1);
-            let y: P::BaseField = if parity(&y) != parity(u) { -y } else { y };
-            let point = Affine::<P>::new_unchecked(x, y);
-            return point;
-        }
-
-        let x2: P::BaseField = u2_mul_z.mul(&x1);
-        let gx2: P::BaseField = x2.square().add(&Self::A);
-        let gx2: P::BaseField = gx2.mul(&x2).add(&Self::B);
-        let (x, y) = Self::isogeny_map(&x2, &gx2);
-        let y: P::BaseField = if parity(&y) != parity(u) { -y } else { y };
-        let point = Affine::<P>::new_unchecked(x, y);
-        return point;
+        let tv1: P::BaseField = denominator::<P, Self>(u)
+            .inverse()
+            .unwrap_or_else(P::BaseField::zero);
+        let (x, y) = map_with_inverse::<P, Self>(u, &tv1);
+        Self::isogeny_map(&x, &y)
     }
 
-    /// Mapping an arbitrary message to a field element,
-    /// This step matching step 1
-    fn hash_to_field<H: Default + DynDigest + Clone>(msg: &[u8], dst: &[u8]) -> Vec<P::BaseField> {
-        let field_hasher = <DefaultFieldHasher<H> as HashToField<P::BaseField>>::new(dst);
-
-        let field_elems: Vec<P::BaseField> = field_hasher.hash_to_field(msg, 2);
+    /// Mapping the field elements to the points on the elliptic curve as `map_to_curve` does,
+    /// with one inversion for all the denominators by Montgomery's trick and one for the isogeny.
+    ///
+    /// It branches on the inputs, so it is not constant time
+    fn map_to_curve_batch(us: &[P::BaseField]) -> Vec<Affine<P>> {
+        // batch_inversion leaves the zeros, which is inv0
+        let mut tv1: Vec<P::BaseField> = cfg_iter!(us).map(denominator::<P, Self>).collect();
+        batch_inversion(&mut tv1);
 
-        field_elems
+        let points: Vec<(P::BaseField, P::BaseField)> = cfg_iter!(us)
+            .zip(&tv1)
+            .map(|(u, tv1)| map_with_inverse::<P, Self>(u, tv1))
+            .collect();
+        Self::isogeny_map_batch(&points)
     }
 
-    /// Perform hashing to curve
-    ///
-    /// To implement hashing to curve, the following steps are usually required:
-    /// step 1 : u = hash_to_field(msg, 2)
-    /// step 2 : Q0 = map_to_curve(u[0])
-    /// step 3 : Q1 = map_to_curve(u[1])
-    /// step 4 : R = Q0 + Q1
-    /// step 5 : P = clear_cofactor(R)
-    /// step 6 : return P
-    fn hash<H: Default + DynDigest + Clone>(msg: &[u8]) -> Projective<P> {
-        let rand_field_elems: Vec<P::BaseField> = Self::hash_to_field::<H>(msg, Self::DST);
-
-        let rand_curve_elem_0 = Self::map_to_curve(&rand_field_elems[0]);
-        let rand_curve_elem_1 = Self::map_to_curve(&rand_field_elems[1]);
+    /// The constant-time version of `map_to_curve`, which neither branches nor returns early on u,
+    /// see the straight-line implementation of <https://www.rfc-editor.org/rfc/rfc9380.html#section-6.6.2>
+    fn map_to_curve_ct(u: &P::BaseField) -> Affine<P>
+    where
+        P::BaseField: PrimeField,
+    {
+        let (x_num, x_den, y) = sswu(u, &Self::A, &Self::B, &Self::Z);
+        let x: P::BaseField = x_num.mul(&inv0(&x_den));
 
-        let rand_curve_elem: Projective<P> = rand_curve_elem_0.add(&rand_curve_elem_1);
-        let rand_subgroup_elem = rand_curve_elem.mul_bigint(P::COFACTOR);
+        Self::isogeny_map(&x, &y)
+    }
 
-        rand_subgroup_elem
+    /// The isogeny map from isogeny curve to origin curve,
+    /// which maps the affine point (x, y) of the isogeny curve to the origin curve,
+    /// and the points in the kernel of the isogeny to the identity.
+    ///
+    /// It should not branch on (x, y), since `map_to_curve_ct` calls it
+    ///
+    /// The default is the identity map, for the curves where SSWU applies directly
+    fn isogeny_map(x: &P::BaseField, y: &P::BaseField) -> Affine<P> {
+        Affine::<P>::new_unchecked(*x, *y)
     }
 
-    /// The isogeny map from isogeny curve to origin curve
-    fn isogeny_map(x: &P::BaseField, y: &P::BaseField) -> (P::BaseField, P::BaseField);
+    /// The isogeny map of the points from `map_to_curve_batch`,
+    /// the instances with an `Isogeny` override it with `Isogeny::map_affine_batch` to share the inversions
+    fn isogeny_map_batch(points: &[(P::BaseField, P::BaseField)]) -> Vec<Affine<P>> {
+        cfg_iter!(points)
+            .map(|(x, y)| Self::isogeny_map(x, y))
+            .collect()
+    }
 
     /// The constant c1 equals ：
     /// c1 = - B/A
@@ -103,4 +147,130 @@ pub trait SimplifiedSWUMap<P: SWCurveConfig> {
             b_neg.div(&Self::A)
         }
     }
+
+    /// Clearing the cofactor of a point on the curve, this step matching step 5 of hashing to curve
+    ///
+    /// The default multiplies by `H_EFF`, the instances on the curves with an endomorphism
+    /// override it with a faster method, e.g. the psi endomorphism of BLS12-381 G2
+    fn clear_cofactor(point: &Projective<P>) -> Projective<P> {
+        point.mul_bigint(Self::H_EFF)
+    }
+}
+
+/// The denominator Z^2 * u^4 + Z * u^2 of x1 in `map_to_curve`
+fn denominator<P: SWCurveConfig, M: SimplifiedSWUMap<P> + ?Sized>(
+    u: &P::BaseField,
+) -> P::BaseField {
+    let u2_mul_z: P::BaseField = u.square().mul(&M::Z);
+    u2_mul_z.square().add(&u2_mul_z)
+}
+
+/// The point (x, y) on the isogeny curve from u and tv1 = inv0(Z^2 * u^4 + Z * u^2)
+fn map_with_inverse<P: SWCurveConfig, M: SimplifiedSWUMap<P> + ?Sized>(
+    u: &P::BaseField,
+    tv1: &P::BaseField,
+) -> (P::BaseField, P::BaseField) {
+    let u2_mul_z: P::BaseField = u.square().mul(&M::Z);
+
+    // x1 = B / (Z * A) for the exceptional inputs, where tv1 = inv0(0) = 0
+    let x1: P::BaseField = if tv1.is_zero() {
+        M::B.div(M::Z.mul(&M::A))
+    } else {
+        M::c1().mul(&tv1.add(&P::BaseField::one()))
+    };
+    let gx1: P::BaseField = x1.square().add(&M::A);
+    let gx1: P::BaseField = gx1.mul(&x1).add(&M::B);
+    let (x, y) = if gx1.legendre().is_qr() {
+        (x1, gx1.sqrt().unwrap())
+    } else {
+        let x2: P::BaseField = u2_mul_z.mul(&x1);
+        let gx2: P::BaseField = x2.square().add(&M::A);
+        let gx2: P::BaseField = gx2.mul(&x2).add(&M::B);
+        (x2, gx2.sqrt().unwrap())
+    };
+
+    let y: P::BaseField = if parity(&y) != parity(u) { -y } else { y };
+    (x, y)
+}
+
+/// Hashing to curve with the Simplified SWU map `M`, e.g. `SimplifiedSWUHashToCurve<Secp256K1SSWUMap>`,
+/// which is the `HashToCurve` of the Simplified SWU map family
+pub struct SimplifiedSWUHashToCurve<M>(PhantomData<M>);
+
+impl<P: SWCurveConfig, M: SimplifiedSWUMap<P>> HashToCurve<Projective<P>>
+    for SimplifiedSWUHashToCurve<M>
+{
+    const DST: &'static [u8] = M::DST;
+    type FieldHasher = M::FieldHasher;
+
+    fn map_to_curve(u: &P::BaseField) -> Projective<P> {
+        M::map_to_curve(u).into()
+    }
+
+    fn map_to_curve_batch(us: &[P::BaseField]) -> Vec<Affine<P>> {
+        M::map_to_curve_batch(us)
+    }
+
+    fn clear_cofactor(point: &Projective<P>) -> Projective<P> {
+        M::clear_cofactor(point)
+    }
+}
+
+/// The `MapToCurve` of arkworks, so that the Simplified SWU maps can be plugged into
+/// `MapToCurveBasedHasher` and the libraries built on arkworks
+impl<P: SWCurveConfig, M: SimplifiedSWUMap<P>> MapToCurve<Projective<P>>
+    for SimplifiedSWUHashToCurve<M>
+{
+    fn new() -> Result<Self, HashToCurveError> {
+        if M::Z.legendre().is_qr() {
+            return Err(HashToCurveError::MapToCurveError(
+                "Z should be a quadratic non-residue for the Simplified SWU map".to_string(),
+            ));
+        }
+        if M::A.is_zero() || M::B.is_zero() {
+            return Err(HashToCurveError::MapToCurveError(
+                "A * B should be nonzero for the Simplified SWU map".to_string(),
+            ));
+        }
+
+        Ok(Self(PhantomData))
+    }
+
+    fn map_to_curve(&self, point: P::BaseField) -> Result<Affine<P>, HashToCurveError> {
+        Ok(M::map_to_curve(&point))
+    }
+}
+
+impl<P: SWCurveConfig, M: SimplifiedSWUMap<P>> SimplifiedSWUMap<P> for ConstantTime<M>
+where
+    P::BaseField: PrimeField,
+{
+    const Z: P::BaseField = M::Z;
+    const C1: Option<P::BaseField> = M::C1;
+    const A: P::BaseField = M::A;
+    const B: P::BaseField = M::B;
+    const DST: &'static [u8] = M::DST;
+    type FieldHasher = M::FieldHasher;
+    const H_EFF: &'static [u64] = M::H_EFF;
+
+    fn map_to_curve(u: &P::BaseField) -> Affine<P> {
+        M::map_to_curve_ct(u)
+    }
+
+    /// The batch maps each element in constant time, since the batch inversion branches on zeros
+    fn map_to_curve_batch(us: &[P::BaseField]) -> Vec<Affine<P>> {
+        cfg_iter!(us).map(M::map_to_curve_ct).collect()
+    }
+
+    fn isogeny_map(x: &P::BaseField, y: &P::BaseField) -> Affine<P> {
+        M::isogeny_map(x, y)
+    }
+
+    fn isogeny_map_batch(points: &[(P::BaseField, P::BaseField)]) -> Vec<Affine<P>> {
+        M::isogeny_map_batch(points)
+    }
+
+    fn clear_cofactor(point: &Projective<P>) -> Projective<P> {
+        M::clear_cofactor(point)
+    }
 }
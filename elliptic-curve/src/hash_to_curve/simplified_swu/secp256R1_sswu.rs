@@ -0,0 +1,23 @@
+use ark_ff::MontFp;
+use ark_secp256r1::Fq;
+use sha2::Sha256;
+
+use crate::hash_to_curve::field_hasher::XmdFieldHasher;
+
+use super::SimplifiedSWUMap;
+
+/// The Simplified SWU map for P-256, which applies directly to the curve since A != 0 and B != 0,
+/// so the parameters A, B and the isogeny map take the defaults.
+pub struct Secp256R1SSWUMap;
+
+impl SimplifiedSWUMap<ark_secp256r1::Config> for Secp256R1SSWUMap {
+    const Z: Fq = MontFp!("-10");
+
+    const C1: Option<Fq> = None;
+
+    const DST: &'static [u8] = b"secp256r1_sswu";
+
+    /// The hash_to_field function of the suites `P256_XMD:SHA-256_SSWU_RO_`
+    /// and `P256_XMD:SHA-256_SSWU_NU_` of RFC 9380
+    type FieldHasher = XmdFieldHasher<Sha256>;
+}
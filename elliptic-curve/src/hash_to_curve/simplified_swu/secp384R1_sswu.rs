@@ -0,0 +1,23 @@
+use ark_ff::MontFp;
+use ark_secp384r1::{Config, Fq};
+use sha2::Sha384;
+
+use crate::hash_to_curve::field_hasher::XmdFieldHasher;
+
+use super::SimplifiedSWUMap;
+
+/// The Simplified SWU map for P-384, which applies directly to the curve since A != 0 and B != 0,
+/// so the parameters A, B and the isogeny map take the defaults.
+pub struct Secp384R1SSWUMap;
+
+impl SimplifiedSWUMap<Config> for Secp384R1SSWUMap {
+    const Z: Fq = MontFp!("-12");
+
+    const C1: Option<Fq> = None;
+
+    const DST: &'static [u8] = b"secp384r1_sswu";
+
+    /// The hash_to_field function of the suites `P384_XMD:SHA-384_SSWU_RO_`
+    /// and `P384_XMD:SHA-384_SSWU_NU_` of RFC 9380
+    type FieldHasher = XmdFieldHasher<Sha384, 192>;
+}
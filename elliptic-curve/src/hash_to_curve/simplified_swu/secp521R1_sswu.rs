@@ -0,0 +1,23 @@
+use ark_ff::MontFp;
+use sha2::Sha512;
+
+use crate::curves::secp521r1::{Config, Fq};
+use crate::hash_to_curve::field_hasher::XmdFieldHasher;
+
+use super::SimplifiedSWUMap;
+
+/// The Simplified SWU map for P-521, which applies directly to the curve since A != 0 and B != 0,
+/// so the parameters A, B and the isogeny map take the defaults.
+pub struct Secp521R1SSWUMap;
+
+impl SimplifiedSWUMap<Config> for Secp521R1SSWUMap {
+    const Z: Fq = MontFp!("-4");
+
+    const C1: Option<Fq> = None;
+
+    const DST: &'static [u8] = b"secp521r1_sswu";
+
+    /// The hash_to_field function of the suites `P521_XMD:SHA-512_SSWU_RO_`
+    /// and `P521_XMD:SHA-512_SSWU_NU_` of RFC 9380
+    type FieldHasher = XmdFieldHasher<Sha512, 256>;
+}
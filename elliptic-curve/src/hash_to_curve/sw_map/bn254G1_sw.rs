@@ -0,0 +1,37 @@
+use ark_bn254::g1::Config;
+use ark_bn254::Fq;
+use ark_ff::MontFp;
+use sha2::Sha256;
+
+use crate::hash_to_curve::field_hasher::XmdFieldHasher;
+
+use super::SWMap;
+
+/// The SW map for BN254 G1: y^2 = x^3 + 3, where A = 0 and the curve has no convenient isogeny.
+///
+/// Z = 1 is the output of find_z_svdw of RFC 9380.
+pub struct Bn254G1SWMap;
+
+impl SWMap<Config> for Bn254G1SWMap {
+    const Z: Fq = MontFp!("1");
+
+    const C1: Option<Fq> = Some(MontFp!("4"));
+
+    const C2: Option<Fq> = Some(MontFp!(
+        "10944121435919637611123202872628637544348155578648911831344518947322613104291"
+    ));
+
+    const C3: Option<Fq> = Some(MontFp!(
+        "8815841940592487685674414971303048083897117035520822607866"
+    ));
+
+    const C4: Option<Fq> = Some(MontFp!(
+        "7296080957279758407415468581752425029565437052432607887563012631548408736189"
+    ));
+
+    const DST: &'static [u8] = b"bn254g1_sw";
+
+    /// The hash_to_field function of the suites `BN254G1_XMD:SHA-256_SVDW_RO_`
+    /// and `BN254G1_XMD:SHA-256_SVDW_NU_`
+    type FieldHasher = XmdFieldHasher<Sha256>;
+}
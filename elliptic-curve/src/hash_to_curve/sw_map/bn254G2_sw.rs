@@ -0,0 +1,86 @@
+use ark_bn254::g2::Config;
+use ark_bn254::Fq2;
+use ark_ec::short_weierstrass::Projective;
+use ark_ec::Group;
+use ark_ff::MontFp;
+use sha2::Sha256;
+
+use crate::hash_to_curve::field_hasher::XmdFieldHasher;
+
+use super::SWMap;
+
+/// The SW map for BN254 G2 over Fp2 = Fp[I] / (I^2 + 1): y^2 = x^3 + 3 / (9 + I), where A = 0.
+///
+/// Z = 1 is the output of find_z_svdw of RFC 9380 over Fp2, and an element c0 + c1 * I of Fp2 is `Fq2::new(c0, c1)`.
+/// The cofactor of G2 is cleared by the psi endomorphism of Fuentes-Castañeda et al. as gnark does,
+/// which is much faster than the multiplication by the 254-bit cofactor.
+pub struct Bn254G2SWMap;
+
+/// The BN parameter x = 4965661367192848881
+const X: &[u64] = &[0x44e992b44a6909f1];
+
+/// psi_x = xi^((p - 1) / 3) for xi = 9 + I
+const PSI_X: Fq2 = Fq2::new(
+    MontFp!("21575463638280843010398324269430826099269044274347216827212613867836435027261"),
+    MontFp!("10307601595873709700152284273816112264069230130616436755625194854815875713954"),
+);
+
+/// psi_y = xi^((p - 1) / 2) for xi = 9 + I
+const PSI_Y: Fq2 = Fq2::new(
+    MontFp!("2821565182194536844548159561693502659359617185244120367078079554186484126554"),
+    MontFp!("3505843767911556378687030309984248845540243509899259641013678093033130930403"),
+);
+
+/// The endomorphism psi = twist^-1 * frobenius * twist of G2, which is
+/// psi(x, y) = (conj(x) * psi_x, conj(y) * psi_y) in the Jacobian coordinates as well
+fn psi(point: &Projective<Config>) -> Projective<Config> {
+    let mut x = point.x;
+    let mut y = point.y;
+    let mut z = point.z;
+    x.conjugate_in_place();
+    y.conjugate_in_place();
+    z.conjugate_in_place();
+    Projective::new_unchecked(x * PSI_X, y * PSI_Y, z)
+}
+
+impl SWMap<Config> for Bn254G2SWMap {
+    const Z: Fq2 = Fq2::new(MontFp!("1"), MontFp!("0"));
+
+    const C1: Option<Fq2> = Some(Fq2::new(
+        MontFp!("19485874751759354771024239261021720505790618469301721065564631296452457478374"),
+        MontFp!("266929791119991161246907387137283842545076965332900288569378510910307636690"),
+    ));
+
+    const C2: Option<Fq2> = Some(Fq2::new(
+        MontFp!("10944121435919637611123202872628637544348155578648911831344518947322613104291"),
+        MontFp!("0"),
+    ));
+
+    const C3: Option<Fq2> = Some(Fq2::new(
+        MontFp!("18992192239972082890849143911285057164064277369389217330423471574879236301292"),
+        MontFp!("21819008332247140148575583693947636719449476128975323941588917397607662637108"),
+    ));
+
+    const C4: Option<Fq2> = Some(Fq2::new(
+        MontFp!("10499238450719652342378357227399831140106360636427411350395554762472100376473"),
+        MontFp!("6940174569119770192419592065569379906172001098655407502803841283667998553941"),
+    ));
+
+    const DST: &'static [u8] = b"bn254g2_sw";
+
+    /// The hash_to_field function of the suites `BN254G2_XMD:SHA-256_SVDW_RO_`
+    /// and `BN254G2_XMD:SHA-256_SVDW_NU_`
+    ///
+    /// Each element of Fp2 is hashed from 2 * 48 bytes (m = 2, L = 48).
+    type FieldHasher = XmdFieldHasher<Sha256>;
+
+    /// [x]P + psi([3x]P) + psi^2([x]P) + psi^3(P), which is the multiplication by a multiple of
+    /// the cofactor coprime to r, see <https://eprint.iacr.org/2008/530>
+    fn clear_cofactor(point: &Projective<Config>) -> Projective<Config> {
+        let x_p = point.mul_bigint(X);
+        let psi_3x_p = psi(&(x_p.double() + x_p));
+        let psi2_x_p = psi(&psi(&x_p));
+        let psi3_p = psi(&psi(&psi(point)));
+        x_p + psi_3x_p + psi2_x_p + psi3_p
+    }
+}
@@ -1,17 +1,42 @@
 use ark_ec::hashing::curve_maps::swu::parity;
+use ark_ec::hashing::map_to_curve_hasher::MapToCurve;
+use ark_ec::hashing::HashToCurveError;
 use ark_ec::short_weierstrass::Affine;
 use ark_ec::short_weierstrass::Projective;
 use ark_ec::short_weierstrass::SWCurveConfig;
 use ark_ec::Group;
-use ark_ff::field_hashers::{DefaultFieldHasher, HashToField};
+use ark_ff::batch_inversion;
+use ark_ff::field_hashers::HashToField;
 use ark_ff::Field;
-use sha2::digest::DynDigest;
-use std::ops::*;
+use ark_ff::Zero;
+use ark_ff::{FftField, PrimeField};
+use ark_std::cfg_iter;
+use ark_std::marker::PhantomData;
+use ark_std::ops::*;
+use ark_std::{string::ToString, vec::Vec};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use super::constant_time::{cmov, inv0, is_square, sqrt_ratio, ConstantTime};
+use super::hasher::HashToCurve;
 
 /// Module for the secp256K1 instance of the SW map"
 #[allow(non_snake_case)]
 pub mod secp256K1_sw;
 
+/// Module for the BN254 G1 instance of the SW map
+#[allow(non_snake_case)]
+pub mod bn254G1_sw;
+
+/// Module for the BN254 G2 instance of the SW map
+#[allow(non_snake_case)]
+pub mod bn254G2_sw;
+
+/// Module for the ElligatorSwift encoding of secp256K1 built on the SW map
+#[allow(non_snake_case)]
+pub mod secp256K1_ellswift;
+
 /// Trait for implementing a general Shallue–van de Woestijne map that is effective for most short Weierstrass form of:
 /// g(x) = y^2 = x^3 + a * x +b.
 ///
@@ -36,81 +61,85 @@ pub trait SWMap<P: SWCurveConfig> {
     /// The constant c4
     const C4: Option<P::BaseField>;
 
-    /// Domain separation
+    /// The default domain separation tag,
+    /// applications should separate their own domains by `hash_with_dst` or a `Hasher`
     const DST: &'static [u8];
 
+    /// The hash_to_field function of the suite, e.g. `XmdFieldHasher<Sha256>`,
+    /// which `HashToCurve::hash_to_curve` and `HashToCurve::encode_to_curve` use
+    type FieldHasher: HashToField<P::BaseField>;
+
+    /// The effective cofactor h_eff of RFC 9380 in little-endian limbs,
+    /// which is the cofactor of the curve by default
+    const H_EFF: &'static [u64] = P::COFACTOR;
+
     /// Mapping an arbitrary field element to a point on the elliptic curve,
     /// This step matching step 2 and step 3
+    ///
+    /// The exceptional inputs with 1 - u^2 * c1 = 0 or 1 + u^2 * c1 = 0 are handled by `inv0`,
+    /// see <https://www.rfc-editor.org/rfc/rfc9380.html#section-6.6.1>
     fn map_to_curve(u: &P::BaseField) -> Affine<P> {
+        // inv0: the inverse of zero is zero, so x1 = x2 = c2 and x3 = Z for the exceptional inputs
+        let tv3: P::BaseField = denominator::<P, Self>(u)
+            .inverse()
+            .unwrap_or_else(P::BaseField::zero);
+        map_with_inverse::<P, Self>(u, &tv3)
+    }
+
+    /// Mapping the field elements to the points on the elliptic curve as `map_to_curve` does,
+    /// with one inversion for all the denominators by Montgomery's trick.
+    ///
+    /// It branches on the inputs, so it is not constant time
+    fn map_to_curve_batch(us: &[P::BaseField]) -> Vec<Affine<P>> {
+        // batch_inversion leaves the zeros, which is inv0
+        let mut tv3: Vec<P::BaseField> = cfg_iter!(us).map(denominator::<P, Self>).collect();
+        batch_inversion(&mut tv3);
+
+        cfg_iter!(us)
+            .zip(&tv3)
+            .map(|(u, tv3)| map_with_inverse::<P, Self>(u, tv3))
+            .collect()
+    }
+
+    /// The constant-time version of `map_to_curve`, which neither branches nor returns early on u,
+    /// see the straight-line implementation of <https://www.rfc-editor.org/rfc/rfc9380.html#section-6.6.1>
+    fn map_to_curve_ct(u: &P::BaseField) -> Affine<P>
+    where
+        P::BaseField: PrimeField,
+    {
         let a: P::BaseField = P::COEFF_A;
         let b: P::BaseField = P::COEFF_B;
 
         let tv1: P::BaseField = u.square().mul(&Self::c1());
         let tv2: P::BaseField = P::BaseField::ONE.add(&tv1);
         let tv1: P::BaseField = P::BaseField::ONE.sub(&tv1);
-        let tv3: P::BaseField = tv1.mul(&tv2);
-        let tv3: P::BaseField = tv3.inverse().unwrap();
+        let tv3: P::BaseField = inv0(&tv1.mul(&tv2));
         let tv4: P::BaseField = u.mul(&tv1).mul(&tv3).mul(&Self::c3());
 
         let x1: P::BaseField = Self::c2().sub(&tv4);
         let gx1: P::BaseField = x1.square().add(&a);
         let gx1: P::BaseField = gx1.mul(&x1).add(&b);
-        if gx1.legendre().is_qr() {
-            let y: P::BaseField = gx1.sqrt().unwrap();
-            let y: P::BaseField = if parity(&y) != parity(u) { -y } else { y };
-            let point = Affine::<P>::new_unchecked(x1, y);
-            return point;
-        }
+        let e1 = is_square(&gx1);
 
         let x2: P::BaseField = Self::c2().add(&tv4);
         let gx2: P::BaseField = x2.square().add(&a);
         let gx2: P::BaseField = gx2.mul(&x2).add(&b);
-        if gx2.legendre().is_qr() {
-            let y: P::BaseField = gx2.sqrt().unwrap();
-            let y: P::BaseField = if parity(&y) != parity(u) { -y } else { y };
-            let point = Affine::<P>::new_unchecked(x2, y);
-            return point;
-        }
+        let e2 = is_square(&gx2) & !e1;
 
         let x3: P::BaseField = tv2.square().mul(&tv3);
         let x3: P::BaseField = x3.square().mul(&Self::c4()).add(&Self::Z);
-        let gx3: P::BaseField = x3.square().add(&a);
-        let gx3: P::BaseField = gx3.mul(&x3).add(&b);
-        let y: P::BaseField = gx3.sqrt().unwrap();
-        let y: P::BaseField = if parity(&y) != parity(u) { -y } else { y };
-        let point = Affine::<P>::new_unchecked(x3, y);
-        return point;
-    }
-
-    /// Mapping an arbitrary message to a field element,
-    /// This step matching step 1
-    fn hash_to_field<H: Default + DynDigest + Clone>(msg: &[u8], dst: &[u8]) -> Vec<P::BaseField> {
-        let field_hasher = <DefaultFieldHasher<H> as HashToField<P::BaseField>>::new(dst);
-
-        let field_elems: Vec<P::BaseField> = field_hasher.hash_to_field(msg, 2);
-
-        field_elems
-    }
-
-    /// Perform hashing to curve
-    ///
-    /// To implement hashing to curve, the following steps are usually required:
-    /// step 1 : u = hash_to_field(msg, 2)
-    /// step 2 : Q0 = map_to_curve(u[0])
-    /// step 3 : Q1 = map_to_curve(u[1])
-    /// step 4 : R = Q0 + Q1
-    /// step 5 : P = clear_cofactor(R)
-    /// step 6 : return P
-    fn hash<H: Default + DynDigest + Clone>(msg: &[u8]) -> Projective<P> {
-        let rand_field_elems: Vec<P::BaseField> = Self::hash_to_field::<H>(msg, Self::DST);
 
-        let rand_curve_elem_0 = Self::map_to_curve(&rand_field_elems[0]);
-        let rand_curve_elem_1 = Self::map_to_curve(&rand_field_elems[1]);
+        let x: P::BaseField = cmov(&x3, &x1, e1);
+        let x: P::BaseField = cmov(&x, &x2, e2);
+        let gx: P::BaseField = x.square().add(&a);
+        let gx: P::BaseField = gx.mul(&x).add(&b);
 
-        let rand_curve_elem: Projective<P> = rand_curve_elem_0.add(&rand_curve_elem_1);
-        let rand_subgroup_elem = rand_curve_elem.mul_bigint(P::COFACTOR);
+        // gx is square, so sqrt_ratio(gx, 1) = sqrt(gx) with the multiplicative generator as the non-square
+        let (_, y) = sqrt_ratio(&gx, &P::BaseField::ONE, &P::BaseField::GENERATOR);
+        let e3 = parity(u) == parity(&y);
+        let y: P::BaseField = cmov(&y.neg(), &y, e3);
 
-        rand_subgroup_elem
+        Affine::<P>::new_unchecked(x, y)
     }
 
     /// The constant c1 equals ：
@@ -123,9 +152,7 @@ pub trait SWMap<P: SWCurveConfig> {
             let b = P::COEFF_B;
 
             let c1 = Self::Z.mul(&Self::Z).add(&a);
-            let c1 = c1.mul(&Self::Z).add(&b);
-
-            c1
+            c1.mul(&Self::Z).add(&b)
         }
     }
 
@@ -183,10 +210,134 @@ pub trait SWMap<P: SWCurveConfig> {
             let gz = Self::c1();
             let gz_neg = gz.neg();
 
-            let c4 = gz_neg
+            gz_neg
                 .mul(&four)
-                .div(Self::Z.mul(&Self::Z).mul(&three).add(a.mul(&four)));
-            c4
+                .div(Self::Z.mul(&Self::Z).mul(&three).add(a.mul(&four)))
+        }
+    }
+
+    /// Clearing the cofactor of a point on the curve, this step matching step 5 of hashing to curve
+    ///
+    /// The default multiplies by `H_EFF`, the instances on the curves with an endomorphism
+    /// override it with a faster method, e.g. the psi endomorphism of BLS12-381 G2
+    fn clear_cofactor(point: &Projective<P>) -> Projective<P> {
+        point.mul_bigint(Self::H_EFF)
+    }
+}
+
+/// The denominator (1 - u^2 * c1) * (1 + u^2 * c1) of tv3 in `SWMap::map_to_curve`
+fn denominator<P: SWCurveConfig, M: SWMap<P> + ?Sized>(u: &P::BaseField) -> P::BaseField {
+    let tv1: P::BaseField = u.square().mul(&M::c1());
+    P::BaseField::ONE
+        .sub(&tv1)
+        .mul(&P::BaseField::ONE.add(&tv1))
+}
+
+/// The point on the curve from u and tv3 = inv0((1 - u^2 * c1) * (1 + u^2 * c1))
+fn map_with_inverse<P: SWCurveConfig, M: SWMap<P> + ?Sized>(
+    u: &P::BaseField,
+    tv3: &P::BaseField,
+) -> Affine<P> {
+    let a: P::BaseField = P::COEFF_A;
+    let b: P::BaseField = P::COEFF_B;
+
+    let tv1: P::BaseField = u.square().mul(&M::c1());
+    let tv2: P::BaseField = P::BaseField::ONE.add(&tv1);
+    let tv1: P::BaseField = P::BaseField::ONE.sub(&tv1);
+    let tv4: P::BaseField = u.mul(&tv1).mul(tv3).mul(&M::c3());
+
+    let x1: P::BaseField = M::c2().sub(&tv4);
+    let gx1: P::BaseField = x1.square().add(&a);
+    let gx1: P::BaseField = gx1.mul(&x1).add(&b);
+    if gx1.legendre().is_qr() {
+        let y: P::BaseField = gx1.sqrt().unwrap();
+        let y: P::BaseField = if parity(&y) != parity(u) { -y } else { y };
+        let point = Affine::<P>::new_unchecked(x1, y);
+        return point;
+    }
+
+    let x2: P::BaseField = M::c2().add(&tv4);
+    let gx2: P::BaseField = x2.square().add(&a);
+    let gx2: P::BaseField = gx2.mul(&x2).add(&b);
+    if gx2.legendre().is_qr() {
+        let y: P::BaseField = gx2.sqrt().unwrap();
+        let y: P::BaseField = if parity(&y) != parity(u) { -y } else { y };
+        let point = Affine::<P>::new_unchecked(x2, y);
+        return point;
+    }
+
+    let x3: P::BaseField = tv2.square().mul(tv3);
+    let x3: P::BaseField = x3.square().mul(&M::c4()).add(&M::Z);
+    let gx3: P::BaseField = x3.square().add(&a);
+    let gx3: P::BaseField = gx3.mul(&x3).add(&b);
+    let y: P::BaseField = gx3.sqrt().unwrap();
+    let y: P::BaseField = if parity(&y) != parity(u) { -y } else { y };
+    Affine::<P>::new_unchecked(x3, y)
+}
+
+/// Hashing to curve with the SW map `M`, e.g. `SWHashToCurve<Secp256K1SWMap>`,
+/// which is the `HashToCurve` of the SW map family
+pub struct SWHashToCurve<M>(PhantomData<M>);
+
+impl<P: SWCurveConfig, M: SWMap<P>> HashToCurve<Projective<P>> for SWHashToCurve<M> {
+    const DST: &'static [u8] = M::DST;
+    type FieldHasher = M::FieldHasher;
+
+    fn map_to_curve(u: &P::BaseField) -> Projective<P> {
+        M::map_to_curve(u).into()
+    }
+
+    fn map_to_curve_batch(us: &[P::BaseField]) -> Vec<Affine<P>> {
+        M::map_to_curve_batch(us)
+    }
+
+    fn clear_cofactor(point: &Projective<P>) -> Projective<P> {
+        M::clear_cofactor(point)
+    }
+}
+
+/// The `MapToCurve` of arkworks, so that the SW maps can be plugged into
+/// `MapToCurveBasedHasher` and the libraries built on arkworks
+impl<P: SWCurveConfig, M: SWMap<P>> MapToCurve<Projective<P>> for SWHashToCurve<M> {
+    fn new() -> Result<Self, HashToCurveError> {
+        // the map is undefined if g(Z) = 0
+        if M::c1().is_zero() {
+            return Err(HashToCurveError::MapToCurveError(
+                "g(Z) should be nonzero for the SW map".to_string(),
+            ));
         }
+
+        Ok(Self(PhantomData))
+    }
+
+    fn map_to_curve(&self, point: P::BaseField) -> Result<Affine<P>, HashToCurveError> {
+        Ok(M::map_to_curve(&point))
+    }
+}
+
+impl<P: SWCurveConfig, M: SWMap<P>> SWMap<P> for ConstantTime<M>
+where
+    P::BaseField: PrimeField,
+{
+    const Z: P::BaseField = M::Z;
+    const C1: Option<P::BaseField> = M::C1;
+    const C2: Option<P::BaseField> = M::C2;
+    const C3: Option<P::BaseField> = M::C3;
+    const C4: Option<P::BaseField> = M::C4;
+    const DST: &'static [u8] = M::DST;
+    type FieldHasher = M::FieldHasher;
+    const H_EFF: &'static [u64] = M::H_EFF;
+
+    fn map_to_curve(u: &P::BaseField) -> Affine<P> {
+        M::map_to_curve_ct(u)
+    }
+
+    /// The batch maps each element in constant time, since the batch inversion branches on zeros
+    fn map_to_curve_batch(us: &[P::BaseField]) -> Vec<Affine<P>> {
+        cfg_iter!(us).map(M::map_to_curve_ct).collect()
+    }
+
+    fn clear_cofactor(point: &Projective<P>) -> Projective<P> {
+        M::clear_cofactor(point)
     }
 }
@@ -0,0 +1,281 @@
+use ark_ec::hashing::curve_maps::swu::parity;
+use ark_ec::short_weierstrass::{Affine, SWCurveConfig};
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{BigInteger, Field, MontFp, PrimeField, Zero};
+use ark_secp256k1::{Config, Fq, Fr};
+use ark_std::ops::*;
+use sha2::{Digest, Sha256};
+
+use super::secp256K1_sw::Secp256K1SWMap;
+use super::SWMap;
+
+/// ElligatorSwift of BIP-324 for secp256k1, which encodes a public key into 64 bytes
+/// indistinguishable from the uniformly random bytes, and the x-only ECDH over such encodings.
+///
+/// The decoding XSwiftEC is the SwiftEC variant of the SW map `Secp256K1SWMap`,
+/// whose x-coordinates come from the conic parametrized by (u, t) instead of u alone.
+/// The encodings (u, t) are 32-byte big-endian field elements reduced modulo p.
+///
+/// It branches on the inputs as libsecp256k1 does, so it is not constant time,
+/// see <https://github.com/bitcoin/bips/blob/master/bip-0324.mediawiki> and
+/// <https://eprint.iacr.org/2022/759>
+pub struct Secp256K1ElligatorSwift;
+
+impl Secp256K1ElligatorSwift {
+    /// The constant c0 = sqrt(-3)
+    pub const C0: Fq =
+        MontFp!("4602937940656409685400179041082242364498080236264115595900560044423621507154");
+
+    /// The constant c1 = (c0 - 1) / 2
+    pub const C1: Fq =
+        MontFp!("60197513588986302554485582024885075108884032450952339817679072026166228089408");
+
+    /// The constant c2 = (-c0 - 1) / 2
+    pub const C2: Fq =
+        MontFp!("55594575648329892869085402983802832744385952214688224221778511981742606582254");
+
+    /// The constant c3 = (-c0 + 1) / 2
+    pub const C3: Fq =
+        MontFp!("55594575648329892869085402983802832744385952214688224221778511981742606582255");
+
+    /// The constant c4 = (c0 + 1) / 2
+    pub const C4: Fq =
+        MontFp!("60197513588986302554485582024885075108884032450952339817679072026166228089409");
+
+    /// The tag of the tagged hash seeding the encoding, see BIP-340 for the tagged hashes
+    pub const ENCODE_TAG: &'static [u8] = b"secp256k1_ellswift_encode";
+
+    /// The tag of the tagged hash deriving the shared secret of BIP-324
+    pub const XDH_TAG: &'static [u8] = b"bip324_ellswift_xonly_ecdh";
+
+    /// Decoding an encoding (u, t) to the x-coordinate of a point on the curve, i.e. XSwiftEC
+    ///
+    /// - If u = 0, set u = 1.
+    /// - If t = 0, set s = 1, else set s = t^2.
+    /// - Let g = u^3 + 7.
+    /// - If g + s = 0, set s = 4 * s.
+    /// - If x3 = (3 * s * u^3 - (g + s)^2) / (3 * s * u^2) is a valid x-coordinate, return it.
+    /// - If x2 = u * (c1 * s + c2 * g) / (g + s) is a valid x-coordinate, return it.
+    /// - Return x1 = -(x2 + u).
+    pub fn xswiftec(u: &Fq, t: &Fq) -> Fq {
+        let u: Fq = if u.is_zero() { Fq::ONE } else { *u };
+        let mut s: Fq = if t.is_zero() { Fq::ONE } else { t.square() };
+        let g: Fq = u.square().mul(&u).add(&Config::COEFF_B);
+        if g.add(&s).is_zero() {
+            s = s.double().double();
+        }
+        let gs: Fq = g.add(&s);
+
+        // 3 * s * u^2 and g + s are nonzero now
+        let d: Fq = s.mul(&u.square()).mul(&Fq::from(3u64));
+        let x3: Fq = d.mul(&u).sub(&gs.square()).div(&d);
+        if is_x_coordinate(&x3) {
+            return x3;
+        }
+
+        let x2: Fq = u.mul(&Self::C1.mul(&s).add(&Self::C2.mul(&g))).div(&gs);
+        if is_x_coordinate(&x2) {
+            return x2;
+        }
+
+        x2.add(&u).neg()
+    }
+
+    /// Decoding an encoding (u, t) to the point on the curve, whose y-coordinate has the parity of t
+    pub fn swiftec(u: &Fq, t: &Fq) -> Affine<Config> {
+        let x: Fq = Self::xswiftec(u, t);
+        // x is always a valid x-coordinate, the sign of y is fixed below
+        let point = Affine::<Config>::get_point_from_x_unchecked(x, false).unwrap();
+        if parity(&point.y) == parity(t) {
+            point
+        } else {
+            -point
+        }
+    }
+
+    /// Finding t such that (u, t) decodes to the x-coordinate x, i.e. XSwiftECInv
+    ///
+    /// The branch c in 0..8 picks one of the up to 8 preimages, and it returns `None` if the branch has none.
+    /// The branches 0 to 3 match the branches 1 to 4 of the paper, and 4 to 7 negate sqrt(s) of them.
+    /// Every t found maps back to x by `xswiftec`, but u must be nonzero and no t = 0 is returned
+    ///
+    /// - If c & 2 = 0:
+    ///   - If -x - u is a valid x-coordinate, fail.
+    ///   - Let s = -(u^3 + 7) / (u^2 + u * x + x^2), fail if s is not square.
+    ///   - Let v = x.
+    /// - If c & 2 = 2:
+    ///   - Let s = x - u, fail if s is not square.
+    ///   - Let r = sqrt(-s * (4 * (u^3 + 7) + 3 * u^2 * s)), fail if it doesn't exist.
+    ///   - If c & 1 = 1 and r = 0, fail.
+    ///   - If s = 0, fail.
+    ///   - Let v = (r / s - u) / 2.
+    /// - Let w = sqrt(s).
+    /// - Return -w * (c3 * u + v), w * (c4 * u + v), w * (c3 * u + v) or -w * (c4 * u + v)
+    ///   for c & 5 = 0, 1, 4 or 5 respectively.
+    pub fn xswiftec_inv(x: &Fq, u: &Fq, c: u8) -> Option<Fq> {
+        debug_assert!(c < 8 && !u.is_zero() && is_x_coordinate(x));
+
+        let g: Fq = u.square().mul(u).add(&Config::COEFF_B);
+        let (s, v): (Fq, Fq) = if c & 2 == 0 {
+            // -x - u would decode under the formula of x3, which has the priority over x1 and x2
+            if is_x_coordinate(&x.add(u).neg()) {
+                return None;
+            }
+
+            // u^2 + u * x + x^2 is nonzero since x^3 + 7 is square and (-x - u)^3 + 7 is not
+            let s: Fq = g.neg().div(&u.square().add(&u.mul(x)).add(&x.square()));
+            if !is_square(&s) {
+                return None;
+            }
+            (s, *x)
+        } else {
+            let s: Fq = x.sub(u);
+            if !is_square(&s) {
+                return None;
+            }
+
+            let q: Fq = g
+                .double()
+                .double()
+                .add(&u.square().mul(&s).mul(&Fq::from(3u64)));
+            let r: Fq = q.mul(&s).neg().sqrt()?;
+            if (c & 1 == 1 && r.is_zero()) || s.is_zero() {
+                return None;
+            }
+
+            // v = (r / s - u) / 2, where -1 / 2 is c2 of the SW map with Z = 1
+            let v: Fq = r.div(&s).sub(u).mul(&Secp256K1SWMap::c2().neg());
+            (s, v)
+        };
+
+        let w: Fq = s.sqrt()?;
+        let w: Fq = if c & 5 == 0 || c & 5 == 5 { w.neg() } else { w };
+        let c34: Fq = if c & 1 == 1 { Self::C4 } else { Self::C3 };
+        Some(w.mul(&c34.mul(u).add(&v)))
+    }
+
+    /// Encoding a public key into 64 bytes u || t with the randomness `rnd32`, whose decoding is the key itself.
+    /// It returns `None` for the point at infinity
+    ///
+    /// The u and the branches are drawn from SHA256 as libsecp256k1 does, so the encodings agree with
+    /// `secp256k1_ellswift_encode` for the same randomness: the hash input is the tagged hash
+    /// of `ENCODE_TAG` over the compressed key padded to 64 bytes, `rnd32` and a 4-byte counter
+    pub fn encode(point: &Affine<Config>, rnd32: &[u8; 32]) -> Option<[u8; 64]> {
+        let (x, y) = point.xy()?;
+
+        let mut key = [0u8; 64];
+        key[0] = if parity(y) { 0x03 } else { 0x02 };
+        key[1..33].copy_from_slice(&field_to_bytes(x));
+        let hasher = tagged_hash(Self::ENCODE_TAG)
+            .chain_update(key)
+            .chain_update(rnd32);
+        let prng = |cnt: u32| -> [u8; 32] {
+            hasher
+                .clone()
+                .chain_update(cnt.to_le_bytes())
+                .finalize()
+                .into()
+        };
+
+        // a pool of 64 branches of 4 bits is drawn first, then each u is drawn with a branch,
+        // which takes 4 tries on average
+        let mut branch_hash = [0u8; 32];
+        let mut branches_left = 0usize;
+        let mut cnt = 0u32;
+        let (u32, t) = loop {
+            if branches_left == 0 {
+                branch_hash = prng(cnt);
+                cnt += 1;
+                branches_left = 64;
+            }
+            branches_left -= 1;
+            let branch = (branch_hash[branches_left >> 1] >> ((branches_left & 1) << 2)) & 7;
+
+            let u32 = prng(cnt);
+            cnt += 1;
+            // u is a hash output, which is practically never zero
+            let u = Fq::from_be_bytes_mod_order(&u32);
+            if let Some(t) = Self::xswiftec_inv(x, &u, branch) {
+                break (u32, t);
+            }
+        };
+
+        // the parity of t carries the parity of y
+        let t: Fq = if parity(&t) == parity(y) { t } else { t.neg() };
+
+        let mut ell64 = [0u8; 64];
+        ell64[..32].copy_from_slice(&u32);
+        ell64[32..].copy_from_slice(&field_to_bytes(&t));
+        Some(ell64)
+    }
+
+    /// Decoding 64 bytes u || t to the public key, every 64 bytes decode to a point on the curve
+    pub fn decode(ell64: &[u8; 64]) -> Affine<Config> {
+        let (u, t) = split(ell64);
+        Self::swiftec(&u, &t)
+    }
+
+    /// The x-only ECDH of BIP-324 between the encodings `ell_a64` of the initiator and `ell_b64` of the responder,
+    /// where the party holding `seckey` is the initiator if `initiating` is true.
+    /// It returns `None` if `seckey` is zero
+    ///
+    /// The shared secret is the tagged hash of `XDH_TAG` over ell_a64 || ell_b64 || x,
+    /// where x is the x-coordinate of `seckey` times the decoding of the encoding of the other party
+    pub fn xdh(
+        ell_a64: &[u8; 64],
+        ell_b64: &[u8; 64],
+        seckey: &Fr,
+        initiating: bool,
+    ) -> Option<[u8; 32]> {
+        if seckey.is_zero() {
+            return None;
+        }
+
+        // the x-coordinate of the product doesn't depend on the sign of y
+        let (u, t) = split(if initiating { ell_b64 } else { ell_a64 });
+        let x: Fq = Self::xswiftec(&u, &t);
+        let point = Affine::<Config>::get_point_from_x_unchecked(x, false)?;
+        let shared = point.mul_bigint(seckey.into_bigint()).into_affine();
+        let (x, _) = shared.xy()?;
+
+        Some(
+            tagged_hash(Self::XDH_TAG)
+                .chain_update(ell_a64)
+                .chain_update(ell_b64)
+                .chain_update(field_to_bytes(x))
+                .finalize()
+                .into(),
+        )
+    }
+}
+
+/// Whether x is the x-coordinate of a point on the curve, i.e. x^3 + 7 is square
+fn is_x_coordinate(x: &Fq) -> bool {
+    is_square(&x.square().mul(x).add(&Config::COEFF_B))
+}
+
+/// Whether the field element is square, zero included
+fn is_square(x: &Fq) -> bool {
+    !x.legendre().is_qnr()
+}
+
+/// The field elements u and t of the 64 bytes u || t, each 32 bytes are reduced modulo p
+fn split(ell64: &[u8; 64]) -> (Fq, Fq) {
+    (
+        Fq::from_be_bytes_mod_order(&ell64[..32]),
+        Fq::from_be_bytes_mod_order(&ell64[32..]),
+    )
+}
+
+/// The 32-byte big-endian encoding of the field element
+fn field_to_bytes(x: &Fq) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    bytes.copy_from_slice(&x.into_bigint().to_bytes_be());
+    bytes
+}
+
+/// SHA256 fed with SHA256(tag) || SHA256(tag), see BIP-340 for the tagged hashes
+fn tagged_hash(tag: &[u8]) -> Sha256 {
+    let tag_hash = Sha256::digest(tag);
+    Sha256::new().chain_update(tag_hash).chain_update(tag_hash)
+}
@@ -0,0 +1,248 @@
+use ark_bls12_381::{g1, g2, Fq as Bls12381Fq, Fq2 as Bls12381Fq2};
+use ark_ec::hashing::curve_maps::swu::SWUConfig;
+use ark_ec::hashing::curve_maps::wb::{IsogenyMap, WBConfig, WBMap};
+use ark_ec::hashing::map_to_curve_hasher::{MapToCurve, MapToCurveBasedHasher};
+use ark_ec::hashing::HashToCurve as ArkHashToCurve;
+use ark_ec::short_weierstrass::{Affine as SWAffine, Projective as SWProjective, SWCurveConfig};
+use ark_ec::twisted_edwards::Projective as TEProjective;
+use ark_ec::{AffineRepr, CurveConfig};
+use ark_ed25519::EdwardsConfig;
+use ark_ff::{MontFp, One, Zero};
+use ark_secp256k1::{Fq, Fr};
+use ark_std::test_rng;
+use ark_std::UniformRand;
+use sha2::{Sha256, Sha512};
+
+use crate::hash_to_curve::elligator2::edwards25519_ell2::Edwards25519Ell2Map;
+use crate::hash_to_curve::elligator2::Elligator2HashToCurve;
+use crate::hash_to_curve::field_hasher::XmdFieldHasher;
+use crate::hash_to_curve::hasher::HashToCurve;
+use crate::hash_to_curve::optimized_swu::bls12381G1_oswu::Bls12381G1OSWUMap;
+use crate::hash_to_curve::optimized_swu::secp256K1_oswu::Secp256K1OSWUMap;
+use crate::hash_to_curve::optimized_swu::OptimizedSWUHashToCurve;
+use crate::hash_to_curve::simplified_swu::bls12381G1_sswu::Bls12381G1SSWUMap;
+use crate::hash_to_curve::simplified_swu::bls12381G2_sswu::Bls12381G2SSWUMap;
+use crate::hash_to_curve::simplified_swu::secp256K1_sswu::Secp256K1SSWUMap;
+use crate::hash_to_curve::simplified_swu::SimplifiedSWUHashToCurve;
+use crate::hash_to_curve::sw_map::secp256K1_sw::Secp256K1SWMap;
+use crate::hash_to_curve::sw_map::SWHashToCurve;
+
+const MSGS: [&[u8]; 3] = [b"", b"abc", b"abcdef0123456789"];
+
+/// secp256k1 as the codomain of the WB map of arkworks,
+/// the curve is the same as `ark_secp256k1::Config` which can not implement `WBConfig` here
+struct Secp256k1WBConfig;
+
+impl CurveConfig for Secp256k1WBConfig {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+
+    const COFACTOR: &'static [u64] = &[1];
+    const COFACTOR_INV: Fr = MontFp!("1");
+}
+
+impl SWCurveConfig for Secp256k1WBConfig {
+    const COEFF_A: Fq = MontFp!("0");
+    const COEFF_B: Fq = MontFp!("7");
+
+    const GENERATOR: SWAffine<Self> =
+        SWAffine::new_unchecked(ark_secp256k1::G_GENERATOR_X, ark_secp256k1::G_GENERATOR_Y);
+}
+
+/// E': y'^2 = x'^3 + A' * x' + B', the 3-isogenous curve of secp256k1,
+/// see <https://www.rfc-editor.org/rfc/rfc9380.html#appendix-E.1>
+struct Secp256k1IsoConfig;
+
+impl CurveConfig for Secp256k1IsoConfig {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+
+    const COFACTOR: &'static [u64] = &[1];
+    const COFACTOR_INV: Fr = MontFp!("1");
+}
+
+impl SWCurveConfig for Secp256k1IsoConfig {
+    const COEFF_A: Fq =
+        MontFp!("28734576633528757162648956269730739219262246272443394170905244663053633733939");
+    const COEFF_B: Fq = MontFp!("1771");
+
+    const GENERATOR: SWAffine<Self> = SWAffine::new_unchecked(
+        MontFp!("1"),
+        MontFp!("10931162814563479916725657473632386416945440111739392308145419224535295140289"),
+    );
+}
+
+impl SWUConfig for Secp256k1IsoConfig {
+    const ZETA: Fq = MontFp!("-11");
+}
+
+/// The 3-isogeny map from E' to secp256k1 of RFC 9380, in ascending order of degree
+impl WBConfig for Secp256k1WBConfig {
+    type IsogenousCurve = Secp256k1IsoConfig;
+
+    const ISOGENY_MAP: IsogenyMap<'static, Secp256k1IsoConfig, Self> = IsogenyMap {
+        x_map_numerator: &[
+            MontFp!(
+                "64328938465175664124206102782604393251816658147578091133031991115504908150983"
+            ),
+            MontFp!("3540463234204664767867377763959255381561641196938647754971861192896365225345"),
+            MontFp!(
+                "37676595701789655284650173187508961899444205326770530105295841645151729341026"
+            ),
+            MontFp!(
+                "64328938465175664124206102782604393251816658147578091133031991115504908150924"
+            ),
+        ],
+        x_map_denominator: &[
+            MontFp!(
+                "95592507323525948732419199626899895302164312317343489384240252208201861084315"
+            ),
+            MontFp!(
+                "107505182841474506714709588670204841388457878609653642868747406790547894725908"
+            ),
+            MontFp!("1"),
+        ],
+        y_map_numerator: &[
+            MontFp!(
+                "34308767181427020866243254817389009734302217678708315270950395261602617680444"
+            ),
+            MontFp!(
+                "90176424683627901097894375140309208301239340832535417794535213712559228940707"
+            ),
+            MontFp!(
+                "18838297850894827642325086593754480949722102663385265052647920822575864670513"
+            ),
+            MontFp!(
+                "21442979488391888041402034260868131083938886049192697044343997038501636050308"
+            ),
+        ],
+        y_map_denominator: &[
+            MontFp!(
+                "115792089237316195423570985008687907853269984665640564039457584007908834670907"
+            ),
+            MontFp!(
+                "55193343495945455350115628863323870199952967620749340073805588608787913909619"
+            ),
+            MontFp!(
+                "45465685024895564648493397996619354229416833248839900263663526177913007417199"
+            ),
+            MontFp!("1"),
+        ],
+    };
+}
+
+#[test]
+fn test_secp256k1_maps_against_wb_map() {
+    let wb = WBMap::<Secp256k1WBConfig>::new().unwrap();
+    let sswu = SimplifiedSWUHashToCurve::<Secp256K1SSWUMap>::new().unwrap();
+    let oswu = OptimizedSWUHashToCurve::<Secp256K1OSWUMap>::new().unwrap();
+
+    let mut rng = test_rng();
+    let mut inputs: Vec<Fq> = (0..100).map(|_| Fq::rand(&mut rng)).collect();
+    inputs.extend([Fq::zero(), Fq::one(), -Fq::one()]);
+    for u in inputs {
+        let expected = wb.map_to_curve(u).unwrap();
+        for point in [sswu.map_to_curve(u).unwrap(), oswu.map_to_curve(u).unwrap()] {
+            assert_eq!(point.xy(), expected.xy());
+        }
+    }
+}
+
+#[test]
+fn test_secp256k1_maps_in_map_to_curve_based_hasher() {
+    let dst = b"QUUX-V01-CS02-with-secp256k1_XMD:SHA-256_SSWU_RO_";
+    let wb = MapToCurveBasedHasher::<
+        SWProjective<Secp256k1WBConfig>,
+        XmdFieldHasher<Sha256>,
+        WBMap<Secp256k1WBConfig>,
+    >::new(dst)
+    .unwrap();
+    let sswu = MapToCurveBasedHasher::<
+        SWProjective<ark_secp256k1::Config>,
+        XmdFieldHasher<Sha256>,
+        SimplifiedSWUHashToCurve<Secp256K1SSWUMap>,
+    >::new(dst)
+    .unwrap();
+    let oswu = MapToCurveBasedHasher::<
+        SWProjective<ark_secp256k1::Config>,
+        XmdFieldHasher<Sha256>,
+        OptimizedSWUHashToCurve<Secp256K1OSWUMap>,
+    >::new(dst)
+    .unwrap();
+    let sw = MapToCurveBasedHasher::<
+        SWProjective<ark_secp256k1::Config>,
+        XmdFieldHasher<Sha256>,
+        SWHashToCurve<Secp256K1SWMap>,
+    >::new(dst)
+    .unwrap();
+
+    for msg in MSGS {
+        let expected = SimplifiedSWUHashToCurve::<Secp256K1SSWUMap>::hash_with_dst::<
+            XmdFieldHasher<Sha256>,
+        >(msg, dst);
+        assert_eq!(wb.hash(msg).unwrap().xy(), SWAffine::from(expected).xy());
+        assert_eq!(sswu.hash(msg).unwrap(), expected);
+        assert_eq!(oswu.hash(msg).unwrap(), expected);
+
+        assert_eq!(
+            sw.hash(msg).unwrap(),
+            SWHashToCurve::<Secp256K1SWMap>::hash_with_dst::<XmdFieldHasher<Sha256>>(msg, dst)
+        );
+    }
+}
+
+#[test]
+fn test_bls12381_maps_against_wb_map() {
+    let mut rng = test_rng();
+
+    let wb = WBMap::<g1::Config>::new().unwrap();
+    let sswu = SimplifiedSWUHashToCurve::<Bls12381G1SSWUMap>::new().unwrap();
+    let oswu = OptimizedSWUHashToCurve::<Bls12381G1OSWUMap>::new().unwrap();
+    for _ in 0..100 {
+        let u = Bls12381Fq::rand(&mut rng);
+        let expected = wb.map_to_curve(u).unwrap();
+        assert_eq!(sswu.map_to_curve(u).unwrap(), expected);
+        assert_eq!(oswu.map_to_curve(u).unwrap(), expected);
+    }
+
+    let wb = WBMap::<g2::Config>::new().unwrap();
+    let sswu = SimplifiedSWUHashToCurve::<Bls12381G2SSWUMap>::new().unwrap();
+    for _ in 0..100 {
+        let u = Bls12381Fq2::rand(&mut rng);
+        assert_eq!(sswu.map_to_curve(u).unwrap(), wb.map_to_curve(u).unwrap());
+    }
+
+    let dst = b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_RO_";
+    let hasher = MapToCurveBasedHasher::<
+        SWProjective<g2::Config>,
+        XmdFieldHasher<Sha256>,
+        WBMap<g2::Config>,
+    >::new(dst)
+    .unwrap();
+    for msg in MSGS {
+        assert_eq!(
+            hasher.hash(msg).unwrap(),
+            SimplifiedSWUHashToCurve::<Bls12381G2SSWUMap>::hash_with_dst::<XmdFieldHasher<Sha256>>(
+                msg, dst
+            )
+        );
+    }
+}
+
+#[test]
+fn test_elligator2_in_map_to_curve_based_hasher() {
+    let dst = b"QUUX-V01-CS02-with-edwards25519_XMD:SHA-512_ELL2_RO_";
+    let hasher = MapToCurveBasedHasher::<
+        TEProjective<EdwardsConfig>,
+        XmdFieldHasher<Sha512>,
+        Elligator2HashToCurve<Edwards25519Ell2Map>,
+    >::new(dst)
+    .unwrap();
+
+    for msg in MSGS {
+        assert_eq!(
+            hasher.hash(msg).unwrap(),
+            Elligator2HashToCurve::<Edwards25519Ell2Map>::hash_to_curve(msg, dst)
+        );
+    }
+}
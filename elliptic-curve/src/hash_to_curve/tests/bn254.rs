@@ -0,0 +1,599 @@
+use ark_bn254::{Fq, Fq2, Fr, G1Affine, G2Affine, G2Projective};
+use ark_ec::{AffineRepr, Group};
+use ark_ff::{BigInteger, Field, PrimeField};
+use ark_std::test_rng;
+use ark_std::UniformRand;
+use sha2::Sha256;
+
+use super::{
+    affine_from_hex, field_from_hex, fq2_from_hex, g2_from_hex, G2NuTestVector, G2RoTestVector,
+};
+use crate::hash_to_curve::constant_time::ConstantTime;
+use crate::hash_to_curve::field_hasher::XmdFieldHasher;
+use crate::hash_to_curve::hasher::{HashToCurve, Hasher};
+use crate::hash_to_curve::sw_map::bn254G1_sw::Bn254G1SWMap;
+use crate::hash_to_curve::sw_map::bn254G2_sw::Bn254G2SWMap;
+use crate::hash_to_curve::sw_map::{SWHashToCurve, SWMap};
+
+/// A test vector of a random oracle suite without the outputs of map_to_curve
+struct SvdwTestVector {
+    msg: &'static str,
+    p: [&'static str; 2],
+    u: [&'static str; 2],
+}
+
+const BN254G1_XMD_SHA256_SVDW_RO_DST: &[u8] = b"QUUX-V01-CS02-with-BN254G1_XMD:SHA-256_SVDW_RO_";
+
+/// The outputs of the independent SvdW implementation of the sylow crate for BN254 G1
+const BN254G1_XMD_SHA256_SVDW_RO: [SvdwTestVector; 5] = [
+    SvdwTestVector {
+        msg: "",
+        p: [
+            "0a976ab906170db1f9638d376514dbf8c42aef256a54bbd48521f20749e59e86",
+            "02925ead66b9e68bfc309b014398640ab55f6619ab59bc1fab2210ad4c4d53d5",
+        ],
+        u: [
+            "2f87b81d9d6ef05ad4d249737498cc27e1bd485dca804487844feb3c67c1a9b5",
+            "06de2d0d7c0d9c7a5a6c0b74675e7543f5b98186b5dbf831067449000b2b1f8e",
+        ],
+    },
+    SvdwTestVector {
+        msg: "abc",
+        p: [
+            "23f717bee89b1003957139f193e6be7da1df5f1374b26a4643b0378b5baf53d1",
+            "04142f826b71ee574452dbc47e05bc3e1a647478403a7ba38b7b93948f4e151d",
+        ],
+        u: [
+            "11945105b5e3d3b9392b5a2318409cbc28b7246aa47fa30da5739907737799a9",
+            "1255fc9ad5a6e0fb440916f091229bda611c41be2f2283c3d8f98c596be4c8c9",
+        ],
+    },
+    SvdwTestVector {
+        msg: "abcdef0123456789",
+        p: [
+            "187dbf1c3c89aceceef254d6548d7163fdfa43084145f92c4c91c85c21442d4a",
+            "0abd99d5b0000910b56058f9cc3b0ab0a22d47cf27615f588924fac1e5c63b4d",
+        ],
+        u: [
+            "2f7993a6b43a8dbb37060e790011a888157f456b895b925c3568690685f4983d",
+            "2677d0532b47a4cead2488845e7df7ebc16c0b8a2cd8a6b7f4ce99f51659794e",
+        ],
+    },
+    SvdwTestVector {
+        msg: "q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq",
+        p: [
+            "00fe2b0743575324fc452d590d217390ad48e5a16cf051bee5c40a2eba233f5c",
+            "0794211e0cc72d3cbbdf8e4e5cd6e7d7e78d101ff94862caae8acbe63e9fdc78",
+        ],
+        u: [
+            "2a50be15282ee276b76db1dab761f75401cdc8bd9fff81fcf4d428db16092a7b",
+            "23b41953676183c30aca54b5c8bd3ffe3535a6238c39f6b15487a5467d5d20eb",
+        ],
+    },
+    SvdwTestVector {
+        msg: "a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+        p: [
+            "01b05dc540bd79fd0fea4fbb07de08e94fc2e7bd171fe025c479dc212a2173ce",
+            "1bf028afc00c0f843d113758968f580640541728cfc6d32ced9779aa613cd9b0",
+        ],
+        u: [
+            "048527470f534978bae262c0f3ba8380d7f560916af58af9ad7dcb6a4238e633",
+            "19a6d8be25702820b9b11eada2d42f425343889637a01ecd7672fbcf590d9ffe",
+        ],
+    },
+];
+
+const BN254G2_XMD_SHA256_SVDW_RO_DST: &[u8] = b"QUUX-V01-CS02-with-BN254G2_XMD:SHA-256_SVDW_RO_";
+
+/// The vectors of gnark-crypto for BN254 G2 in `ecc/bn254/hash_vectors_test.go`, with u, Q0 and Q1
+/// from the gnark-compatible SvdW implementation of the bn254_hash2curve crate
+const BN254G2_XMD_SHA256_SVDW_RO: [G2RoTestVector; 5] = [
+    G2RoTestVector {
+        msg: "",
+        p: [
+            [
+                "1192005a0f121921a6d5629946199e4b27ff8ee4d6dd4f9581dc550ade851300",
+                "1747d950a6f23c16156e2171bce95d1189b04148ad12628869ed21c96a8c9335",
+            ],
+            [
+                "0498f6bb5ac309a07d9a8b88e6ff4b8de0d5f27a075830e1eb0e68ea318201d8",
+                "2c9755350ca363ef2cf541005437221c5740086c2e909b71d075152484e845f4",
+            ],
+        ],
+        u: [
+            [
+                "2c85988ecf26034a6d6c495c467150aeaead51fceb623aa99b0433275c8952c7",
+                "182126b31e6df7cf33844bf16a92f42072ee47f80539dace68dbfc3380d1fcbd",
+            ],
+            [
+                "1c3035901eab4768d522b3d0eb7e58b05c130603c8f43587345dc51745fa3533",
+                "23597b1c4f238038ba6579d203e7fcb7d427c63d4e0d037185453168718203bb",
+            ],
+        ],
+        q0: [
+            [
+                "071e460ff150e978d833ef69fdf228f0d2c0807e3dce076b17dccdaa64bf6b25",
+                "0ab3b378f44776bd951140bfc354e68554ca76a4369a6b20d0da39e18e31fa38",
+            ],
+            [
+                "2c6cdc66602f181b70022028cd584f9d021eb409af5bfcef716a180383140aaf",
+                "113b1e8168192dc9a8048152b61aab936ce3654bf5f67d3d63f53d4eee72e011",
+            ],
+        ],
+        q1: [
+            [
+                "039f9c639d9261f6d96487bae68e2336ba7ed68af727960c371caa330f0f3c05",
+                "1bf10eb5452db5be04eb3469440f9008017f1c632252b13069a3a9aa6c7467ec",
+            ],
+            [
+                "229827ca645e88cccdf70f001f3051f4148bcbc1165796f8550ef055a211d685",
+                "04ffb54e9e9f23b1c84d262f273518f14a8873f4589d2227575d5c65141da706",
+            ],
+        ],
+    },
+    G2RoTestVector {
+        msg: "abc",
+        p: [
+            [
+                "16c88b54eec9af86a41569608cd0f60aab43464e52ce7e6e298bf584b94fccd2",
+                "0b5db3ca7e8ef5edf3a33dfc3242357fbccead98099c3eb564b3d9d13cba4efd",
+            ],
+            [
+                "1c42ba524cb74db8e2c680449746c028f7bea923f245e69f89256af2d6c5f3ac",
+                "22d02d2da7f288545ff8789e789902245ab08c6b1d253561eec789ec2c1bd630",
+            ],
+        ],
+        u: [
+            [
+                "234b244ed36d5acbb96a4f5fb67094945a0bb4ecf33d55bcc218ce834dc82c63",
+                "04ca11f51d0cf7e7393a0e6d7be3d0e6b07652d5ba308554a72dafe502dd59cc",
+            ],
+            [
+                "1c31ec87881353ec57fc87c27e31099a0705390c52dbfc8c047d14260658df71",
+                "2daa8e05eb3367285b5de508d248b3153207498f3e9e51cbe6183ff7dae286a6",
+            ],
+        ],
+        q0: [
+            [
+                "254d44345e73654a4a41adc0b17f39b397c352693513b3439afe5596cba3c6b2",
+                "2d489087e8025d60a201c109bd6be0aac5e8b04593c1127e4f8cf9e654dd1f82",
+            ],
+            [
+                "00f1b1989fb5b87287ba1eee6b04426b1b3afb72c0aa8e981e392e740c0b2045",
+                "20d48c7925d6e00cf89487c737f49a0b5946158ca515fcc12516aefd33f9a45b",
+            ],
+        ],
+        q1: [
+            [
+                "1af57e1f34420bf4fc5d2d880fd69f8c58b0ff2647b9d8b3d98f03fe45300ae8",
+                "164ff536dd42039dbd2f6351f445cd76cb1a346ea1347cfd98500ec62996c94d",
+            ],
+            [
+                "304eeaafb7429b8fe754a567cf23c0d04be055baeb0e9a3a6d34e433f3aa8027",
+                "168b97f3e2a1bbe114931e35f3abd3614f99a58abb4ae0adda944c09d1bdc0e6",
+            ],
+        ],
+    },
+    G2RoTestVector {
+        msg: "abcdef0123456789",
+        p: [
+            [
+                "1435fd84aa43c699230e371f6fea3545ce7e053cbbb06a320296a2b81efddc70",
+                "2a8a360585b6b05996ef69c3c09b2c6fb17afe2b1e944f07559c53178eabf171",
+            ],
+            [
+                "2820188dcdc13ffdca31694942418afa1d6dfaaf259d012fab4da52b0f592e38",
+                "142f08e2441ec431defc24621b73cfe0252d19b243cb55b84bdeb85de039207a",
+            ],
+        ],
+        u: [
+            [
+                "29c7f821157ab18e589d1e7d7bd393d20aff69af2ac4deadc7950998d594d201",
+                "0860010a5c2ae9289f0d4f7099ff0d5904ded06f99d5960f734de36b82ff983c",
+            ],
+            [
+                "1f3c50c3ccfbaad8e81f8a765c5465a034b55fb873be48fd60dc21fb2cca98b8",
+                "02fa095cba1059ef5e2d5ea1c976a87f4530225aa7759b5b9510bb76d7b1d4f3",
+            ],
+        ],
+        q0: [
+            [
+                "0100476fddb9ea779a6fb6d42e56309214d17e9f977e55817d90d174c25da1da",
+                "119928ea6db28a02b97ffd78ca301352f59bf218283c4636ffd8630424d715f2",
+            ],
+            [
+                "1f8b75179bb45ec7dce4e80a6e5ff343354405fb37e0f00f05b6bd4576fe7325",
+                "217dc1c62afca9b764d6aad37652d2ceca98082e8a91278665fc69aa1086f42c",
+            ],
+        ],
+        q1: [
+            [
+                "02eea8de62a9fe65f771b334f09895a941513447befc908c9bd92e379413f705",
+                "2116b794a45df430772983535769ee30a6b16383f402a45bfd061091423771c4",
+            ],
+            [
+                "1a236124a4be9b04860439e8ca5ff9c2b7309473b2235193befcd61c9e911b88",
+                "2abc966940a34cdd457e0505ea3884e90a804cee8b01b510f319fb5f5447c90c",
+            ],
+        ],
+    },
+    G2RoTestVector {
+        msg: "q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq",
+        p: [
+            [
+                "2cffc213fb63d00d923cb22cda5a2904837bb93a2fe6e875c532c51744388341",
+                "2718ef38d1bc4347f0266c774c8ef4ee5fa7056cc27a4bd7ecf7a888efb95b26",
+            ],
+            [
+                "232553f728341afa64ce66d00535764557a052e38657594e10074ad28728c584",
+                "2206ec0a9288f31ed78531c37295df3b56c42a1284443ee9893adb1521779001",
+            ],
+        ],
+        u: [
+            [
+                "0859e4f9b60f7ce13f81da9da46435c8827ed53f553b4e1804a395af1354b2c7",
+                "0368bfd8f29d990293171aee9be3bc4ad623c54d0db776d0fe87cfd579059a86",
+            ],
+            [
+                "103aa84a49f14d0ca1dfda47fa93a43cece0c267ae8799123d63ccd027772f71",
+                "09ebcb7d529f69c5e7ab096ff1a727ec8bc6c5214ed1784cd7f9e325e121640c",
+            ],
+        ],
+        q0: [
+            [
+                "0c18ed8f507c46c91c3cd68bbe67d84fedddf54aa36a0b724d8993c0e89d3473",
+                "216fd51ee739a5ea4bea5e0d02e3217399e001a1b1192494cad83778b265bf51",
+            ],
+            [
+                "086feb20cd348a7f6b10395367f6a94a7c0b6be76673ab847914302cfbef4c8d",
+                "184f467bdb87df3cf3616b88a2dfd4eb512627a8e7cb00ac4c0f0c256948693d",
+            ],
+        ],
+        q1: [
+            [
+                "2ab06564fee17a6d71b4cb24b73798d44711fdd101f6368fdc53e34fb2a3e411",
+                "1924dbd030b8093ac48e7363505d25c53cb0a21f96d5d2e6c534b8e541c2f332",
+            ],
+            [
+                "0089c25648c64971fc868a1c5ca178e336147f26d2984221ed1df72b2c1b49b1",
+                "0af10b749194f436828978b2428c7944d46f8fb8bc34461794bc1bc1d636003a",
+            ],
+        ],
+    },
+    G2RoTestVector {
+        msg: "a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+        p: [
+            [
+                "242a0a159f36f87065e7c5170426012087023165ce47a486e53d6e2845ca625a",
+                "17f9f6292998cf18ccc155903c1fe6b6465d40c794a3e1ed644a4182ad639f4a",
+            ],
+            [
+                "2dc5b7b65c9c79e6ef4afab8fbe3083c66d4ce31c78f6621ece17ecc892cf4b3",
+                "18ef4886c818f01fdf309bc9a46dd904273917f85e74ecd0de62460a68122037",
+            ],
+        ],
+        u: [
+            [
+                "0f0a229a329e3df7fe4feea02aac7dad3a01d345f65efe512544699439aacd83",
+                "15b85241a3f8790e550026f37fd861babd3dba9e2bce0deced2df56f7440bbb4",
+            ],
+            [
+                "0fa59525a85744763ea88a78ca612cb8db4d6e08f3d192568749b90ef16c36b6",
+                "1c32e85696693c537a91a4283353fba8c24f4107278b82990cc0c595a4d4f6cc",
+            ],
+        ],
+        q0: [
+            [
+                "14909a7cf12c368a1ecf7dde981bee058f657b6c47aa2d8bbd0528afac6dbd7b",
+                "03691ff7c610402d3acc2494c72a2a8eb7b34f40f54953201ce87f6c1b0f4bee",
+            ],
+            [
+                "1b4f9ced14ace59a4469280f4ad25c2727cca98c74729f4491bbcd9e3c4ec65f",
+                "26616d464461190482f9583225c483a6df9a7c9bf76bef2c0f02f7b08913cda5",
+            ],
+        ],
+        q1: [
+            [
+                "21641581efa27adfd51aa8605a6e5763c563d929e8157508387bb76239446dbc",
+                "2edc55e80aa268be53526cb82df2eea5aba8595c258b0da6b91e3798d1b901c5",
+            ],
+            [
+                "2e3312775b7af85c4acb0a67fcf5e0a7ea163dd6dae35021d97851dfa9778af4",
+                "0e8d867d428e160f1597b1096f9c492519d9d5e663a4af02f20f272d589804cc",
+            ],
+        ],
+    },
+];
+
+const BN254G2_XMD_SHA256_SVDW_NU_DST: &[u8] = b"QUUX-V01-CS02-with-BN254G2_XMD:SHA-256_SVDW_NU_";
+
+/// The vectors of gnark-crypto for BN254 G2 as above, for the nonuniform encoding
+const BN254G2_XMD_SHA256_SVDW_NU: [G2NuTestVector; 5] = [
+    G2NuTestVector {
+        msg: "",
+        p: [
+            [
+                "04e9ea7f5807198397a99e234e91d4b9e6cadf0135ebedd97fd75cffed6e994d",
+                "070077acfda8443392fb30222ba96b63f4b734e678494bf4ed0e07074b440a7b",
+            ],
+            [
+                "2d3653bf41ec170ce2d48774d02393c8d5f60fee5690b4f8cbc8531e269227f9",
+                "0a7cf5d0d356f0c4d163570209e5f8f749bf91dc2a7d9ba58199a95ce02242b4",
+            ],
+        ],
+        u: [
+            "05952a51e848675c06172da425edc1c471c11db4bc51cfb84c097bdbcf22b6b5",
+            "04f8c1f037b231d08ea68f3e23b8e3c708d3993a1577d1bcfc92c2392a82c47e",
+        ],
+        q: [
+            [
+                "1aff45b8bec4d8c0b48638acd6aa6886d9c0202c8f415061673ec4a1eb520af6",
+                "19abe65fd87b71da2f78347494f28418dee2f1b75956f0dddc4efb98837e325d",
+            ],
+            [
+                "1f01deb60bef6c08b36bb2cb465efdd20b597532e05a27c45034a729f8fe8f59",
+                "2e76aede179fdb62bdba78c6f28566f68aa721f3220a853b65c79f135e427ef7",
+            ],
+        ],
+    },
+    G2NuTestVector {
+        msg: "abc",
+        p: [
+            [
+                "101e2f3d9fa22cb435ecb67d5284dc27c247856d6de4e420e1812e0bcea5afd8",
+                "29226a3ca7415a541599274bf9e805050c82d443fd953481b17236325be3b6b7",
+            ],
+            [
+                "290bf12841dd276211effe86af369c11a2cb364c443981d0faf347cfb7b68715",
+                "2e7c8a61fe36735852597ac564966560afe0ef8221918d5534e57f3096f7047d",
+            ],
+        ],
+        u: [
+            "25f701986d04721d21b118002eeaad1b8ecc8de722d4d8e7ad5f060518ea5c7c",
+            "0f05f22acfb3bf7abb1f8f1b80e0de029a20a2b96c6eefa2f371431bbfca04a3",
+        ],
+        q: [
+            [
+                "2216ec5165748117d5aecf12f1eff5f6380367163e351d591f5de9aebfe5f82e",
+                "2dc39518b60217999eadc27840962dd62ffb38e7d7e6c72a208206e6b2c07028",
+            ],
+            [
+                "0b424f1cc74e01e0d61084ecfb3785bc8a66443d99be786c387a4d5e1ad91832",
+                "0bb538ea60a53b3c933726e61a57e753a8919a75d8c600f8f6af6bbf460a57ee",
+            ],
+        ],
+    },
+    G2NuTestVector {
+        msg: "abcdef0123456789",
+        p: [
+            [
+                "0fcda542dd52f0e527bf828e63fe2a1f63a05c9a5c7a28865cfef247c6e1e8a6",
+                "2d0bb492bb59847c106af8285fae5be0b5f96b6dcad56b3a0c7ddc364ae55a3a",
+            ],
+            [
+                "172d50b483e9bb9aa230e7cb82fbd522af1b73c1643bbd022614533311071780",
+                "0afb68b6e28f44f49d6ab4c3014e73f7e07fd4d0b13a9519b798e9f1927a47b9",
+            ],
+        ],
+        u: [
+            "0eb05b113763043309faadf3c004ac0eb40f948faed5d83d4d1f0571112ca09c",
+            "1730924259ae2e94ae7ee719c1eeb5d6328b6963819ee4065541dfdefb5e7a07",
+        ],
+        q: [
+            [
+                "1f3620223ed7115c479ed1150b509886c8ecf7411e004e943f544829ed3fc56f",
+                "3063a2d037058a1ff49edba42f2f78d3fdd03e592bd3df415d04176ba269c7c7",
+            ],
+            [
+                "1d1a9b20266ba09a594e299ca4c9e27564218986669d1fa90566764f5a8ac63a",
+                "07f48bbaf8c038ad3a2167a9e43e583a73d6a88795e574c4e26834fbcf2bb44b",
+            ],
+        ],
+    },
+    G2NuTestVector {
+        msg: "q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq",
+        p: [
+            [
+                "1d050758368c65df07014cab4752d8244ddf21691ab6418a3493bcc2a946b38d",
+                "2596aa6bcb29439a9cdc7cfe0b9d247a890a4295dc17d053c293c7e40c27387f",
+            ],
+            [
+                "2f84eec5eaa87952d0d81c93c3f470c1e1a00d0ba307d8fda78b76841aca8e82",
+                "27aef639d6eb4157c6f076e9fdae2f9eb15042dea92304fc54ebd5f69c5c3443",
+            ],
+        ],
+        u: [
+            "047b36a3ec43c92ae9070ef71f85016bd5a08c1bd0ca487672f176061ca09159",
+            "248076a8b63f52e5f3c7228411637e04cbd0cb36940ee3a257f60ce49e75fe86",
+        ],
+        q: [
+            [
+                "1db4c6c448341a408bd60ebf8d19bfc149927dbd5af0af598d4c6d2f1629bd8a",
+                "2d61dff76daa91b0044f83074f4c841e0bbf3711f5b8a7ac8e0618b29fce6d92",
+            ],
+            [
+                "1dedea44e27ea061f38fd062e2b1c6d93db96a32cf5a214da49f39eeeb803791",
+                "113a4cf8a33642670164f11b2ef257f6653ae8045ac58fc172f022f3f8107c4a",
+            ],
+        ],
+    },
+    G2NuTestVector {
+        msg: "a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+        p: [
+            [
+                "013729abbd4fbe2a13bc742960afa9053a4e6be06ea712b0d18153a9ec3854a7",
+                "261e8ebaff3438064599465bb52880e8e8a663b27cfb6d794d90ac60437819a9",
+            ],
+            [
+                "132285a30dc36cc14da2d145390a6328e574155ebaece32856fb890d1f7ba16e",
+                "06bd9197b3c0c1cc4d17695042dcbaf0168329a113d358c3b17885f71a394986",
+            ],
+        ],
+        u: [
+            "2f3b24a712fbb1272e51db197d666cdad2cc94c2a6e7b77d99e97d8a705a8a50",
+            "253bcb542b718219fe2f6de276c6d86965d610b3e66bd0448576db18e1e9ab3f",
+        ],
+        q: [
+            [
+                "2376cb494d599bbe8523817d9702f0da6e6e0ba7f321dcccb720cd5696f57de7",
+                "2228f04e096f6802387d0d40b3b575afd66281de5d734b33da09fbb86cef5a1a",
+            ],
+            [
+                "17332e7ce9faf26d7f5c5cf247ea51f48594238faeb0be338462895016da6738",
+                "0cbd6f12f78c47d3f46238782e5d297ab15a1b8f5c065a8ba52620c16d555fa0",
+            ],
+        ],
+    },
+];
+
+#[test]
+fn test_bn254g1_xmd_sha256_svdw_ro() {
+    let dst = BN254G1_XMD_SHA256_SVDW_RO_DST;
+
+    for v in BN254G1_XMD_SHA256_SVDW_RO.iter() {
+        let msg = v.msg.as_bytes();
+        let u: Vec<Fq> =
+            SWHashToCurve::<Bn254G1SWMap>::hash_to_field::<XmdFieldHasher<Sha256>>(msg, dst, 2);
+        assert_eq!(u, vec![field_from_hex(v.u[0]), field_from_hex(v.u[1])]);
+
+        let p: G1Affine = affine_from_hex(&v.p);
+        assert_eq!(
+            G1Affine::from(SWHashToCurve::<Bn254G1SWMap>::hash_to_curve(msg, dst)),
+            p
+        );
+
+        let p_ct = SWHashToCurve::<ConstantTime<Bn254G1SWMap>>::hash_with_dst::<
+            XmdFieldHasher<Sha256>,
+        >(msg, dst);
+        assert_eq!(G1Affine::from(p_ct), p);
+    }
+
+    let msgs: Vec<&[u8]> = BN254G1_XMD_SHA256_SVDW_RO
+        .iter()
+        .map(|v| v.msg.as_bytes())
+        .collect();
+    let ps: Vec<G1Affine> = BN254G1_XMD_SHA256_SVDW_RO
+        .iter()
+        .map(|v| affine_from_hex(&v.p))
+        .collect();
+    assert_eq!(
+        SWHashToCurve::<Bn254G1SWMap>::hash_batch_with_dst::<XmdFieldHasher<Sha256>>(&msgs, dst),
+        ps
+    );
+}
+
+#[test]
+fn test_bn254g1_sw_map() {
+    let mut rng = test_rng();
+    for _ in 0..100 {
+        let u = Fq::rand(&mut rng);
+        let q = Bn254G1SWMap::map_to_curve(&u);
+        assert!(q.is_on_curve());
+        assert_eq!(Bn254G1SWMap::map_to_curve_ct(&u), q);
+    }
+}
+
+#[test]
+fn test_bn254g2_sw_map() {
+    let mut rng = test_rng();
+    for _ in 0..100 {
+        let u = Fq2::rand(&mut rng);
+        let q = Bn254G2SWMap::map_to_curve(&u);
+        assert!(q.is_on_curve());
+    }
+
+    // the exceptional inputs with 1 - u^2 * c1 = 0 are handled by inv0
+    let c1_inv = Bn254G2SWMap::c1().inverse().unwrap();
+    if let Some(u) = c1_inv.sqrt() {
+        assert!(Bn254G2SWMap::map_to_curve(&u).is_on_curve());
+    }
+    assert!(Bn254G2SWMap::map_to_curve(&Fq2::from(0u64)).is_on_curve());
+}
+
+#[test]
+fn test_bn254g2_xmd_sha256_svdw_ro() {
+    let dst = BN254G2_XMD_SHA256_SVDW_RO_DST;
+    let hasher =
+        Hasher::<G2Projective, SWHashToCurve<Bn254G2SWMap>, XmdFieldHasher<Sha256>>::new(dst);
+
+    for v in BN254G2_XMD_SHA256_SVDW_RO.iter() {
+        let msg = v.msg.as_bytes();
+
+        // hash_to_field over Fp2 takes m = 2 base field elements for each output
+        let u: Vec<Fq2> =
+            SWHashToCurve::<Bn254G2SWMap>::hash_to_field::<XmdFieldHasher<Sha256>>(msg, dst, 2);
+        assert_eq!(u, vec![fq2_from_hex(&v.u[0]), fq2_from_hex(&v.u[1])]);
+
+        let q0: G2Affine = g2_from_hex(&v.q0);
+        let q1: G2Affine = g2_from_hex(&v.q1);
+        assert_eq!(Bn254G2SWMap::map_to_curve(&u[0]), q0);
+        assert_eq!(Bn254G2SWMap::map_to_curve(&u[1]), q1);
+        assert!(!q0.is_in_correct_subgroup_assuming_on_curve());
+
+        let p: G2Affine = g2_from_hex(&v.p);
+        assert!(p.is_in_correct_subgroup_assuming_on_curve());
+        assert_eq!(
+            G2Affine::from(SWHashToCurve::<Bn254G2SWMap>::hash_to_curve(msg, dst)),
+            p
+        );
+        assert_eq!(G2Affine::from(hasher.hash(msg)), p);
+    }
+
+    let msgs: Vec<&[u8]> = BN254G2_XMD_SHA256_SVDW_RO
+        .iter()
+        .map(|v| v.msg.as_bytes())
+        .collect();
+    let ps: Vec<G2Affine> = BN254G2_XMD_SHA256_SVDW_RO
+        .iter()
+        .map(|v| g2_from_hex(&v.p))
+        .collect();
+    assert_eq!(hasher.hash_batch(&msgs), ps);
+}
+
+#[test]
+fn test_bn254g2_xmd_sha256_svdw_nu() {
+    let dst = BN254G2_XMD_SHA256_SVDW_NU_DST;
+    let hasher =
+        Hasher::<G2Projective, SWHashToCurve<Bn254G2SWMap>, XmdFieldHasher<Sha256>>::new(dst);
+
+    for v in BN254G2_XMD_SHA256_SVDW_NU.iter() {
+        let msg = v.msg.as_bytes();
+
+        let u: Vec<Fq2> =
+            SWHashToCurve::<Bn254G2SWMap>::hash_to_field::<XmdFieldHasher<Sha256>>(msg, dst, 1);
+        assert_eq!(u, vec![fq2_from_hex(&v.u)]);
+
+        let q: G2Affine = g2_from_hex(&v.q);
+        assert_eq!(Bn254G2SWMap::map_to_curve(&u[0]), q);
+
+        // the cofactor of G2 is cleared by the psi endomorphism as gnark-crypto does
+        let p: G2Affine = g2_from_hex(&v.p);
+        assert!(p.is_in_correct_subgroup_assuming_on_curve());
+        assert_eq!(G2Affine::from(Bn254G2SWMap::clear_cofactor(&q.into())), p);
+        assert_eq!(
+            G2Affine::from(SWHashToCurve::<Bn254G2SWMap>::encode_to_curve(msg, dst)),
+            p
+        );
+        assert_eq!(G2Affine::from(hasher.encode(msg)), p);
+    }
+}
+
+#[test]
+fn test_bn254g2_clear_cofactor() {
+    // psi acts on G2 as the multiplication by p, so the clearing on G2 is the multiplication by
+    // x + 3 * x * p + x * p^2 + p^3 for the BN parameter x
+    let x = Fr::from(4965661367192848881u64);
+    let p = Fr::from_le_bytes_mod_order(&Fq::MODULUS.to_bytes_le());
+    let h = x + x * p * Fr::from(3u64) + x * p.square() + p.square() * p;
+    let g = G2Projective::generator();
+    assert_eq!(Bn254G2SWMap::clear_cofactor(&g), g * h);
+
+    let mut rng = test_rng();
+    for _ in 0..10 {
+        let q = Bn254G2SWMap::map_to_curve(&Fq2::rand(&mut rng));
+        let p = G2Affine::from(Bn254G2SWMap::clear_cofactor(&q.into()));
+        assert!(!p.is_zero());
+        assert!(p.is_on_curve());
+        assert!(p.is_in_correct_subgroup_assuming_on_curve());
+    }
+}
@@ -0,0 +1,376 @@
+use ark_curve25519::{Curve25519Config, EdwardsAffine as Curve25519Affine};
+use ark_ec::twisted_edwards::{Affine as TEAffine, Projective as TEProjective, TECurveConfig};
+use ark_ed25519::{EdwardsAffine, Fq};
+use ark_ff::Zero;
+use ark_std::test_rng;
+use ark_std::UniformRand;
+use sha2::Sha512;
+
+use super::field_from_hex;
+use crate::hash_to_curve::elligator2::curve25519_ell2::Curve25519Ell2Map;
+use crate::hash_to_curve::elligator2::edwards25519_ell2::Edwards25519Ell2Map;
+use crate::hash_to_curve::elligator2::{Elligator2HashToCurve, Elligator2Map};
+use crate::hash_to_curve::field_hasher::XmdFieldHasher;
+use crate::hash_to_curve::hasher::{HashToCurve, Hasher};
+
+/// A test vector of a suite without the outputs of map_to_curve,
+/// `u` holds 2 elements for a random oracle suite and 1 for a nonuniform encoding suite
+struct Ell2TestVector {
+    msg: &'static str,
+    p: [&'static str; 2],
+    u: &'static [&'static str],
+}
+
+fn edwards_from_hex<P: TECurveConfig<BaseField = Fq>>(p: &[&str; 2]) -> TEAffine<P> {
+    let point = TEAffine::<P>::new_unchecked(field_from_hex(p[0]), field_from_hex(p[1]));
+    assert!(point.is_on_curve());
+    point
+}
+
+const EDWARDS25519_XMD_SHA512_ELL2_RO_DST: &[u8] =
+    b"QUUX-V01-CS02-with-edwards25519_XMD:SHA-512_ELL2_RO_";
+
+/// Appendix J.5.1 of RFC 9380
+const EDWARDS25519_XMD_SHA512_ELL2_RO: [Ell2TestVector; 5] = [
+    Ell2TestVector {
+        msg: "",
+        p: [
+            "3c3da6925a3c3c268448dcabb47ccde5439559d9599646a8260e47b1e4822fc6",
+            "09a6c8561a0b22bef63124c588ce4c62ea83a3c899763af26d795302e115dc21",
+        ],
+        u: &[
+            "03fef4813c8cb5f98c6eef88fae174e6e7d5380de2b007799ac7ee712d203f3a",
+            "780bdddd137290c8f589dc687795aafae35f6b674668d92bf92ae793e6a60c75",
+        ],
+    },
+    Ell2TestVector {
+        msg: "abc",
+        p: [
+            "608040b42285cc0d72cbb3985c6b04c935370c7361f4b7fbdb1ae7f8c1a8ecad",
+            "1a8395b88338f22e435bbd301183e7f20a5f9de643f11882fb237f88268a5531",
+        ],
+        u: &[
+            "5081955c4141e4e7d02ec0e36becffaa1934df4d7a270f70679c78f9bd57c227",
+            "005bdc17a9b378b6272573a31b04361f21c371b256252ae5463119aa0b925b76",
+        ],
+    },
+    Ell2TestVector {
+        msg: "abcdef0123456789",
+        p: [
+            "6d7fabf47a2dc03fe7d47f7dddd21082c5fb8f86743cd020f3fb147d57161472",
+            "53060a3d140e7fbcda641ed3cf42c88a75411e648a1add71217f70ea8ec561a6",
+        ],
+        u: &[
+            "285ebaa3be701b79871bcb6e225ecc9b0b32dff2d60424b4c50642636a78d5b3",
+            "2e253e6a0ef658fedb8e4bd6a62d1544fd6547922acb3598ec6b369760b81b31",
+        ],
+    },
+    Ell2TestVector {
+        msg: "q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq",
+        p: [
+            "5fb0b92acedd16f3bcb0ef83f5c7b7a9466b5f1e0d8d217421878ea3686f8524",
+            "2eca15e355fcfa39d2982f67ddb0eea138e2994f5956ed37b7f72eea5e89d2f7",
+        ],
+        u: &[
+            "4fedd25431c41f2a606952e2945ef5e3ac905a42cf64b8b4d4a83c533bf321af",
+            "02f20716a5801b843987097a8276b6d869295b2e11253751ca72c109d37485a9",
+        ],
+    },
+    Ell2TestVector {
+        msg: "a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+        p: [
+            "0efcfde5898a839b00997fbe40d2ebe950bc81181afbd5cd6b9618aa336c1e8c",
+            "6dc2fc04f266c5c27f236a80b14f92ccd051ef1ff027f26a07f8c0f327d8f995",
+        ],
+        u: &[
+            "6e34e04a5106e9bd59f64aba49601bf09d23b27f7b594e56d5de06df4a4ea33b",
+            "1c1c2cb59fc053f44b86c5d5eb8c1954b64976d0302d3729ff66e84068f5fd96",
+        ],
+    },
+];
+
+const EDWARDS25519_XMD_SHA512_ELL2_NU_DST: &[u8] =
+    b"QUUX-V01-CS02-with-edwards25519_XMD:SHA-512_ELL2_NU_";
+
+/// Appendix J.5.2 of RFC 9380
+const EDWARDS25519_XMD_SHA512_ELL2_NU: [Ell2TestVector; 5] = [
+    Ell2TestVector {
+        msg: "",
+        p: [
+            "1ff2b70ecf862799e11b7ae744e3489aa058ce805dd323a936375a84695e76da",
+            "222e314d04a4d5725e9f2aff9fb2a6b69ef375a1214eb19021ceab2d687f0f9b",
+        ],
+        u: &[
+            "7f3e7fb9428103ad7f52db32f9df32505d7b427d894c5093f7a0f0374a30641d",
+        ],
+    },
+    Ell2TestVector {
+        msg: "abc",
+        p: [
+            "5f13cc69c891d86927eb37bd4afc6672360007c63f68a33ab423a3aa040fd2a8",
+            "67732d50f9a26f73111dd1ed5dba225614e538599db58ba30aaea1f5c827fa42",
+        ],
+        u: &[
+            "09cfa30ad79bd59456594a0f5d3a76f6b71c6787b04de98be5cd201a556e253b",
+        ],
+    },
+    Ell2TestVector {
+        msg: "abcdef0123456789",
+        p: [
+            "1dd2fefce934ecfd7aae6ec998de088d7dd03316aa1847198aecf699ba6613f1",
+            "2f8a6c24dd1adde73909cada6a4a137577b0f179d336685c4a955a0a8e1a86fb",
+        ],
+        u: &[
+            "475ccff99225ef90d78cc9338e9f6a6bb7b17607c0c4428937de75d33edba941",
+        ],
+    },
+    Ell2TestVector {
+        msg: "q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq",
+        p: [
+            "35fbdc5143e8a97afd3096f2b843e07df72e15bfca2eaf6879bf97c5d3362f73",
+            "2af6ff6ef5ebba128b0774f4296cb4c2279a074658b083b8dcca91f57a603450",
+        ],
+        u: &[
+            "049a1c8bd51bcb2aec339f387d1ff51428b88d0763a91bcdf6929814ac95d03d",
+        ],
+    },
+    Ell2TestVector {
+        msg: "a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+        p: [
+            "6e5e1f37e99345887fc12111575fc1c3e36df4b289b8759d23af14d774b66bff",
+            "2c90c3d39eb18ff291d33441b35f3262cdd307162cc97c31bfcc7a4245891a37",
+        ],
+        u: &[
+            "3cb0178a8137cefa5b79a3a57c858d7eeeaa787b2781be4a362a2f0750d24fa0",
+        ],
+    },
+];
+
+const CURVE25519_XMD_SHA512_ELL2_RO_DST: &[u8] =
+    b"QUUX-V01-CS02-with-curve25519_XMD:SHA-512_ELL2_RO_";
+
+/// Appendix J.4.1 of RFC 9380, where P is the point (s, t) on the Montgomery curve
+const CURVE25519_XMD_SHA512_ELL2_RO: [Ell2TestVector; 5] = [
+    Ell2TestVector {
+        msg: "",
+        p: [
+            "2de3780abb67e861289f5749d16d3e217ffa722192d16bbd9d1bfb9d112b98c0",
+            "3b5dc2a498941a1033d176567d457845637554a2fe7a3507d21abd1c1bd6e878",
+        ],
+        u: &[
+            "005fe8a7b8fef0a16c105e6cadf5a6740b3365e18692a9c05bfbb4d97f645a6a",
+            "1347edbec6a2b5d8c02e058819819bee177077c9d10a4ce165aab0fd0252261a",
+        ],
+    },
+    Ell2TestVector {
+        msg: "abc",
+        p: [
+            "2b4419f1f2d48f5872de692b0aca72cc7b0a60915dd70bde432e826b6abc526d",
+            "1b8235f255a268f0a6fa8763e97eb3d22d149343d495da1160eff9703f2d07dd",
+        ],
+        u: &[
+            "49bed021c7a3748f09fa8cdfcac044089f7829d3531066ac9e74e0994e05bc7d",
+            "5c36525b663e63389d886105cee7ed712325d5a97e60e140aba7e2ce5ae851b6",
+        ],
+    },
+    Ell2TestVector {
+        msg: "abcdef0123456789",
+        p: [
+            "68ca1ea5a6acf4e9956daa101709b1eee6c1bb0df1de3b90d4602382a104c036",
+            "2a375b656207123d10766e68b938b1812a4a6625ff83cb8d5e86f58a4be08353",
+        ],
+        u: &[
+            "6412b7485ba26d3d1b6c290a8e1435b2959f03721874939b21782df17323d160",
+            "24c7b46c1c6d9a21d32f5707be1380ab82db1054fde82865d5c9e3d968f287b2",
+        ],
+    },
+    Ell2TestVector {
+        msg: "q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq",
+        p: [
+            "096e9c8bae6c06b554c1ee69383bb0e82267e064236b3a30608d4ed20b73ac5a",
+            "1eb5a62612cafb32b16c3329794645b5b948d9f8ffe501d4e26b073fef6de355",
+        ],
+        u: &[
+            "5e123990f11bbb5586613ffabdb58d47f64bb5f2fa115f8ea8df0188e0c9e1b5",
+            "5e8553eb00438a0bb1e7faa59dec6d8087f9c8011e5fb8ed9df31cb6c0d4ac19",
+        ],
+    },
+    Ell2TestVector {
+        msg: "a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+        p: [
+            "1bc61845a138e912f047b5e70ba9606ba2a447a4dade024c8ef3dd42b7bbc5fe",
+            "623d05e47b70e25f7f1d51dda6d7c23c9a18ce015fe3548df596ea9e38c69bf1",
+        ],
+        u: &[
+            "20f481e85da7a3bf60ac0fb11ed1d0558fc6f941b3ac5469aa8b56ec883d6d7d",
+            "017d57fd257e9a78913999a23b52ca988157a81b09c5442501d07fed20869465",
+        ],
+    },
+];
+
+const CURVE25519_XMD_SHA512_ELL2_NU_DST: &[u8] =
+    b"QUUX-V01-CS02-with-curve25519_XMD:SHA-512_ELL2_NU_";
+
+/// Appendix J.4.2 of RFC 9380, where P is the point (s, t) on the Montgomery curve
+const CURVE25519_XMD_SHA512_ELL2_NU: [Ell2TestVector; 5] = [
+    Ell2TestVector {
+        msg: "",
+        p: [
+            "1bb913f0c9daefa0b3375378ffa534bda5526c97391952a7789eb976edfe4d08",
+            "4548368f4f983243e747b62a600840ae7c1dab5c723991f85d3a9768479f3ec4",
+        ],
+        u: &[
+            "608d892b641f0328523802a6603427c26e55e6f27e71a91a478148d45b5093cd",
+        ],
+    },
+    Ell2TestVector {
+        msg: "abc",
+        p: [
+            "7c22950b7d900fa866334262fcaea47a441a578df43b894b4625c9b450f9a026",
+            "5547bc00e4c09685dcbc6cb6765288b386d8bdcb595fa5a6e3969e08097f0541",
+        ],
+        u: &[
+            "46f5b22494bfeaa7f232cc8d054be68561af50230234d7d1d63d1d9abeca8da5",
+        ],
+    },
+    Ell2TestVector {
+        msg: "abcdef0123456789",
+        p: [
+            "31ad08a8b0deeb2a4d8b0206ca25f567ab4e042746f792f4b7973f3ae2096c52",
+            "405070c28e78b4fa269427c82827261991b9718bd6c6e95d627d701a53c30db1",
+        ],
+        u: &[
+            "235fe40c443766ce7e18111c33862d66c3b33267efa50d50f9e8e5d252a40aaa",
+        ],
+    },
+    Ell2TestVector {
+        msg: "q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq",
+        p: [
+            "027877759d155b1997d0d84683a313eb78bdb493271d935b622900459d52ceaa",
+            "54d691731a53baa30707f4a87121d5169fb5d587d70fb0292b5830dedbec4c18",
+        ],
+        u: &[
+            "001e92a544463bda9bd04ddbe3d6eed248f82de32f522669efc5ddce95f46f5b",
+        ],
+    },
+    Ell2TestVector {
+        msg: "a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+        p: [
+            "5fd892c0958d1a75f54c3182a18d286efab784e774d1e017ba2fb252998b5dc1",
+            "750af3c66101737423a4519ac792fb93337bd74ee751f19da4cf1e94f4d6d0b8",
+        ],
+        u: &[
+            "1a68a1af9f663592291af987203393f707305c7bac9c8d63d6a729bdc553dc19",
+        ],
+    },
+];
+
+#[test]
+fn test_edwards25519_xmd_sha512_ell2() {
+    for (dst, vectors) in [
+        (
+            EDWARDS25519_XMD_SHA512_ELL2_RO_DST,
+            &EDWARDS25519_XMD_SHA512_ELL2_RO,
+        ),
+        (
+            EDWARDS25519_XMD_SHA512_ELL2_NU_DST,
+            &EDWARDS25519_XMD_SHA512_ELL2_NU,
+        ),
+    ] {
+        for v in vectors.iter() {
+            let msg = v.msg.as_bytes();
+            let u: Vec<Fq> = Elligator2HashToCurve::<Edwards25519Ell2Map>::hash_to_field::<
+                XmdFieldHasher<Sha512>,
+            >(msg, dst, v.u.len());
+            let expected_u: Vec<Fq> = v.u.iter().map(|u| field_from_hex(u)).collect();
+            assert_eq!(u, expected_u);
+
+            let p: EdwardsAffine = edwards_from_hex(&v.p);
+            let hash = if u.len() == 2 {
+                Elligator2HashToCurve::<Edwards25519Ell2Map>::hash_to_curve(msg, dst)
+            } else {
+                Elligator2HashToCurve::<Edwards25519Ell2Map>::encode_to_curve(msg, dst)
+            };
+            assert_eq!(EdwardsAffine::from(hash), p);
+        }
+    }
+}
+
+#[test]
+fn test_curve25519_xmd_sha512_ell2() {
+    for (dst, vectors) in [
+        (
+            CURVE25519_XMD_SHA512_ELL2_RO_DST,
+            &CURVE25519_XMD_SHA512_ELL2_RO,
+        ),
+        (
+            CURVE25519_XMD_SHA512_ELL2_NU_DST,
+            &CURVE25519_XMD_SHA512_ELL2_NU,
+        ),
+    ] {
+        let hasher = Hasher::<
+            TEProjective<Curve25519Config>,
+            Elligator2HashToCurve<Curve25519Ell2Map>,
+            XmdFieldHasher<Sha512>,
+        >::new(dst);
+
+        for v in vectors.iter() {
+            let msg = v.msg.as_bytes();
+            let u: Vec<Fq> = Elligator2HashToCurve::<Curve25519Ell2Map>::hash_to_field::<
+                XmdFieldHasher<Sha512>,
+            >(msg, dst, v.u.len());
+            let expected_u: Vec<Fq> = v.u.iter().map(|u| field_from_hex(u)).collect();
+            assert_eq!(u, expected_u);
+
+            let (hash, hasher_hash) = if u.len() == 2 {
+                (
+                    Elligator2HashToCurve::<Curve25519Ell2Map>::hash_to_curve(msg, dst),
+                    hasher.hash(msg),
+                )
+            } else {
+                (
+                    Elligator2HashToCurve::<Curve25519Ell2Map>::encode_to_curve(msg, dst),
+                    hasher.encode(msg),
+                )
+            };
+            assert_eq!(hash, hasher_hash);
+
+            let p = Curve25519Affine::from(hash);
+            assert!(p.is_on_curve());
+            assert!(p.is_in_correct_subgroup_assuming_on_curve());
+            let (s, t) = Curve25519Ell2Map::edwards_to_montgomery(&p).unwrap();
+            assert_eq!((s, t), (field_from_hex(v.p[0]), field_from_hex(v.p[1])));
+        }
+    }
+}
+
+#[test]
+fn test_elligator2_map() {
+    let mut rng = test_rng();
+    for _ in 0..100 {
+        let u = Fq::rand(&mut rng);
+
+        // the point (s, t) is on the Montgomery curve K * t^2 = s^3 + J * s^2 + s
+        let (s, t) = Edwards25519Ell2Map::map_to_montgomery(&u);
+        assert_eq!(t * t, s * s * s + Edwards25519Ell2Map::J * s * s + s);
+
+        let q = Edwards25519Ell2Map::map_to_curve(&u);
+        assert!(q.is_on_curve());
+        assert_eq!(Edwards25519Ell2Map::edwards_to_montgomery(&q), Some((s, t)));
+
+        let q = Curve25519Ell2Map::map_to_curve(&u);
+        assert!(q.is_on_curve());
+    }
+
+    assert!(Edwards25519Ell2Map::map_to_curve(&Fq::zero()).is_on_curve());
+
+    // the exceptional point (0, 0) maps to the identity, which has no affine point (s, t)
+    assert_eq!(
+        Edwards25519Ell2Map::montgomery_to_edwards(&Fq::zero(), &Fq::zero()),
+        EdwardsAffine::zero()
+    );
+    assert_eq!(
+        Edwards25519Ell2Map::edwards_to_montgomery(&EdwardsAffine::zero()),
+        None
+    );
+}
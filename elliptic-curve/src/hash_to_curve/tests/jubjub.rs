@@ -0,0 +1,355 @@
+use ark_ec::twisted_edwards::{Affine as TEAffine, Projective as TEProjective, TECurveConfig};
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ed_on_bls12_381::JubjubConfig;
+use ark_ed_on_bls12_381_bandersnatch::BandersnatchConfig;
+use ark_ff::{Field, PrimeField};
+use ark_std::test_rng;
+use ark_std::UniformRand;
+use sha2::Sha256;
+
+use super::{field_from_hex, NuTestVector, RoTestVector};
+use crate::hash_to_curve::elligator2::bandersnatch_ell2::BandersnatchEll2Map;
+use crate::hash_to_curve::elligator2::jubjub_ell2::JubjubEll2Map;
+use crate::hash_to_curve::elligator2::{Elligator2HashToCurve, Elligator2Map};
+use crate::hash_to_curve::field_hasher::XmdFieldHasher;
+use crate::hash_to_curve::hasher::{HashToCurve, Hasher};
+
+/// Computed by `elliptic-curve/isogeny/ell2-vectors.py`, an independent implementation of
+/// section 6.7.1 and appendix D of RFC 9380 in Python integers, with the tag `JubjubEll2Map::DST`
+const JUBJUB_XMD_SHA256_ELL2_RO: [RoTestVector; 3] = [
+    RoTestVector {
+        msg: "",
+        p: [
+            "57579725fdf4aa88da7cb3a9256e87ef2cfb76ce5875ff2c21c46e33ab5e6554",
+            "21bfa80a6e219d7ba7851915be90c45184b7a272fcdd938eed80eb6ef34d0872",
+        ],
+        u: [
+            "2a60804a13735e2fd1c8c30ff7c2570d47e4e05c032e595c60c3dcc841657e09",
+            "0f248f299cbb091c257d63cb37e0c58001de9523ca2ece89c9e2c2495e939bbd",
+        ],
+        q0: [
+            "55f14cc135f8fb8e58e590b62ca83b606bcb73ae13490f0037239641fa69e914",
+            "32fee42efd4aa6e2cffadeb201c0180d07cb06c0b92c17034347ed42343ebe10",
+        ],
+        q1: [
+            "18ba7567d2c759d5de3e8b72fbaa2ce15c1e36468a91b50a22f102266c4c7410",
+            "002ee532ac447e8835114e677f9690f91c9845b2748094cb2be493fcf42297b7",
+        ],
+    },
+    RoTestVector {
+        msg: "abc",
+        p: [
+            "40385491fc2fd6ee8329562f14aa868617fce11ede0bd7d201357f84647f2a89",
+            "2a8cff38bb8c8fe5a44c70854e0b0d09e1370dc1cf9645ba05cff328a7383b87",
+        ],
+        u: [
+            "2515c650244b38735f46488fcf41b9cfa147592ae3de39d5e4255f5a62155165",
+            "662f3704b48f9f11164eaa897799cfce406263ab2f87dee178d657bf1f91ac0c",
+        ],
+        q0: [
+            "07c7e9695b71b8b5e81941a7b12290731fa4abb649ad3ab7d059ebf764194039",
+            "5536914523af39f8cdd4955f03ecec046d1b507dcd1576a11f81a019e76c3ebe",
+        ],
+        q1: [
+            "1ed3afa4c62332689f0b99caa872c6e73e586b8de7b214c11a6e982606af57f8",
+            "6666ba7b3efd17350933c4e3af950236b46faa7878527bbc4f4f04fb207b044a",
+        ],
+    },
+    RoTestVector {
+        msg: "abcdef0123456789",
+        p: [
+            "0be859ec8677ed03cc0df1ce650a0cf27c4cc1489a70d7569bac3840c473feca",
+            "418e3570227fe9e020b727c3c8b4564cbf23cab8cb20ffbed3b27c64d3d615ea",
+        ],
+        u: [
+            "680f716976f145ce53f3c8922e9f824bf2d0eea007796427f1121af9fe9eb5d3",
+            "167c26bccf1b18e3718b3a6815e510a2078f6cdd75d8a3e1080ef6ec3a7ede90",
+        ],
+        q0: [
+            "4d06c78e5fac96de79c19e0b1a58d69099e04665de068dfc88138e3eb248d7fa",
+            "3ba6d0870d08800e08b4cf1e6183fdea22f4b3150a8891746ac2248ad35b45e4",
+        ],
+        q1: [
+            "25c7392218db8649e79f9666da3b30a496b44ae04d825913144b9ad199138e23",
+            "3731833449787dfc1fbecd0fcc4f169f590e92e6673b9b3438423229b7daa588",
+        ],
+    },
+];
+
+/// As above for encoding to curve
+const JUBJUB_XMD_SHA256_ELL2_NU: [NuTestVector; 3] = [
+    NuTestVector {
+        msg: "",
+        p: [
+            "6594ba12d254ef35820ee9d7aa600086c8dad99c2f37a4bd3ee833996881bc06",
+            "2af45d7164fcd9bd65187607e76e9d1e45b3515ad4dc5f7563debfca385a5de6",
+        ],
+        u: "457d06c69e9bdb40c2cc740f755d610441ff4c14192ee4c9db5760605bc4c0bc",
+        q: [
+            "2291732d274e33ec5e435f0915852710ec695a519bed6ae1b3f6b6b195d1874b",
+            "59e743d1486ed703217abdfa687f84b089d53e2bcae5ae105ec3277596e11c74",
+        ],
+    },
+    NuTestVector {
+        msg: "abc",
+        p: [
+            "334fef10f6cf9610406cc3d3f05807c6d3cde0c80c1418cfba016401f5b3c36a",
+            "37c6334f2e3d5b79c7f79e408808de2f97880652007a8c4229607422df7a4b90",
+        ],
+        u: "03d9917ce95948598140e75ec519fa58d03cf893f9664565a73e1093d24f26aa",
+        q: [
+            "26fd1def1f1434ede042b893fcd859c73023e573be1ce3c3ab89acddf40da2f2",
+            "064c621597d05a2adfab145375304ae1b1f6fe290e0e7d2b8a7eaf99d8deaf7c",
+        ],
+    },
+    NuTestVector {
+        msg: "abcdef0123456789",
+        p: [
+            "247a89bfe8f8117f64733d0958bb3f1acd00831932000f81b0144e226f573edf",
+            "1aef0c0f0f3fcba955615ac31b9182fd11880052bedf25fa7836e469ab0b32ae",
+        ],
+        u: "314e9bb4959c80bd08999b4a0ea17a404cba29de6bb83e5a71796b878e70b81a",
+        q: [
+            "63342754a2ce286dc22b047742918bdcdb9c0ca0f1ed5cce758f402abfac4d2d",
+            "320f060e4a9ccea411cbdb1f1eacf039390a1c1bb4854aae83551ce1371786fe",
+        ],
+    },
+];
+
+/// Computed by `elliptic-curve/isogeny/ell2-vectors.py`, an independent implementation of
+/// section 6.7.1 and appendix D of RFC 9380 in Python integers, with the tag `BandersnatchEll2Map::DST`
+const BANDERSNATCH_XMD_SHA256_ELL2_RO: [RoTestVector; 3] = [
+    RoTestVector {
+        msg: "",
+        p: [
+            "0696091fbe7ae66178ec1a913f8b7f5647ad27abcf796da2da29700050040aee",
+            "5335ba1700c315cf95abcca4831c0cb33f29dca2abfb5d5cc99c5170caba0033",
+        ],
+        u: [
+            "0cf474c36f22d660279298d1839ad97fd1041691ec7c3b94be17624dbcb25b0f",
+            "6b0d868d46b10604b88a77fd0c917ad55b0cde97b43f706afebdad2fce28118b",
+        ],
+        q0: [
+            "26705520816b8abc8c488879d3d57383128969949156ef814c96658c0fef226f",
+            "45c5b66aa01bc4f873a560b606764d8aa2af4f53c2ac77ee60021037f6adc4f4",
+        ],
+        q1: [
+            "6b9971a59a76adb82eb5192a488a5af7d373172da40937da32f1222b29cd674f",
+            "644349944620c57eb7fe55116cf03cc9654b4cd48b1978cf3eb975922cd2200f",
+        ],
+    },
+    RoTestVector {
+        msg: "abc",
+        p: [
+            "0e86acdb511b7f4488659e13207f070265c45b5e7bb8bc677e591d55850b9477",
+            "5cf3263121f0b40c67878b7086dacfa37aab8f1dcdbc7a54eca0ec1608b08fde",
+        ],
+        u: [
+            "0188f753876ba8c9863d87b82e79db0a3a00a49e086fb89f258a701a5038d890",
+            "1dcb34394c79b38f2ca530bfd225f152e0627379131bd3f304f8e357f0b82f10",
+        ],
+        q0: [
+            "02df8b3133df96977542f1d429b9f6e77d11a520c8be852b8f2ea0d45bcdaede",
+            "61e6434635f0b4047817549c84a3bbe12333ea4720fcac4ce65f58ca3fa4e586",
+        ],
+        q1: [
+            "615ed9de2604f0bc6c046279b0c136744e366a8e1f15e18531eb9a4f65f2bf19",
+            "088517121b2e6900f005a17ce0974ca555cedb2296a2307d0206c46688220154",
+        ],
+    },
+    RoTestVector {
+        msg: "abcdef0123456789",
+        p: [
+            "1a02a6246362b75f19bde02bc3c35286f43d8adad493927f100581fe91414510",
+            "100e4e18d66896135076607a01dc2a405c71c6d02ccc197d4a2953937a7ee58b",
+        ],
+        u: [
+            "733d61077b0bff6eadefcf1f75c3987398822684c9416e99d87f28f3a90ed66d",
+            "6a738964181fb211a605648e32247d30e312bde9c89d0b6acfcae22a13111780",
+        ],
+        q0: [
+            "3679064de3a2ec71a5918c985d4b880ec219eb03059f8b5e4d22c21ef11c2941",
+            "18ecef93712ec5c40734b3c4471cd8c0efc3c64d264269f8954a888c936e9af8",
+        ],
+        q1: [
+            "2192588667dbff372a871b8b58878760b8d25ee151cc1e8e7eb90522c770821b",
+            "0cffe008f509726a3bf67d20eb88926a4535a7c858cc73ffbc260af21fb2b573",
+        ],
+    },
+];
+
+/// As above for encoding to curve
+const BANDERSNATCH_XMD_SHA256_ELL2_NU: [NuTestVector; 3] = [
+    NuTestVector {
+        msg: "",
+        p: [
+            "5587752dcf40bd6cac0b2ec041599ad0cdbe44b144b443b4f6746730ddf5470e",
+            "5d401a5a1e913a6cc1fd286630fc0a4a3fd612282144d08188dcd0a1e1c6bff3",
+        ],
+        u: "531923d8d571b88e5724b16d06c13a168ddff5c6e40990362e760a39d3c1a81b",
+        q: [
+            "6d3cb794bc90f4cefdab047d6d8bfaee01291f6bb5cc08ea51affef812bd7388",
+            "380fd1124b08d28f32b337b9ad32e599302468455175d5faa372f6c16225fc90",
+        ],
+    },
+    NuTestVector {
+        msg: "abc",
+        p: [
+            "4ef46708fbab09b3cf7b24f0def0c9b81c36631678f00b2223af117dfa228675",
+            "549f60d43e794cec4eb266147ce421fa3d865032396fe3e34172e7a06bfa5ff7",
+        ],
+        u: "486ce212a5f14c52ed9dc1832a61578ee60b4bde799dd7bde1a6afd8a12ce7bf",
+        q: [
+            "220ab7f4b4b00827fdc7446bdae7f22a39972e767a814c67113082a257a449e8",
+            "3884baaf2f1b35631e5fb220972e7338b68725e1261c85ee7d2af2c9d4d80a22",
+        ],
+    },
+    NuTestVector {
+        msg: "abcdef0123456789",
+        p: [
+            "5768dcc87bdc426942748d070aeaf16ccb10b6982290ee95b26748fdb2cb6810",
+            "6d3d270e777c904bdd18de65f66b2efef7daea861414b43c0d036592e64ba442",
+        ],
+        u: "5abccae0a22f416110b488d7ff5c0c9580c88ae334af7932e2225243ebfcf953",
+        q: [
+            "3c5d09fb2cd819e853272dd0a0a4f627fb3131b019b378cd248ed5254ccdb36d",
+            "6402084283d8deeed36f0b26f2146eecb6c2c04eac779a7b3cc6f19adb27519e",
+        ],
+    },
+];
+
+const MSGS: [&[u8]; 3] = [b"", b"abc", b"abcdef0123456789"];
+
+fn te_from_hex<P: TECurveConfig>(p: &[&str; 2]) -> TEAffine<P>
+where
+    P::BaseField: PrimeField,
+{
+    let point = TEAffine::<P>::new_unchecked(field_from_hex(p[0]), field_from_hex(p[1]));
+    assert!(point.is_on_curve());
+    point
+}
+
+/// Check the map and the cofactor clearing of the Elligator 2 map `M` onto `P`,
+/// whose cofactor is not 1, so the points before clearing the cofactor are not all in the subgroup
+fn check_ell2<P, M>()
+where
+    P: TECurveConfig,
+    P::BaseField: PrimeField,
+    M: Elligator2Map<P>,
+{
+    let mut rng = test_rng();
+    for _ in 0..100 {
+        let u = P::BaseField::rand(&mut rng);
+
+        // the point (s, t) is on the Montgomery curve K * t^2 = s^3 + J * s^2 + s
+        let (s, t) = M::map_to_montgomery(&u);
+        assert_eq!(M::K * t.square(), (s + M::J) * s.square() + s);
+        assert!(M::map_to_curve(&u).is_on_curve());
+    }
+
+    let hasher =
+        Hasher::<TEProjective<P>, Elligator2HashToCurve<M>, XmdFieldHasher<Sha256>>::new(M::DST);
+    let mut out_of_subgroup = 0;
+    for msg in MSGS {
+        let u: Vec<P::BaseField> =
+            Elligator2HashToCurve::<M>::hash_to_field::<XmdFieldHasher<Sha256>>(msg, M::DST, 2);
+        let r: TEProjective<P> = M::map_to_curve(&u[0]) + M::map_to_curve(&u[1]);
+        if !r.into_affine().is_in_correct_subgroup_assuming_on_curve() {
+            out_of_subgroup += 1;
+        }
+
+        let p = Elligator2HashToCurve::<M>::hash::<XmdFieldHasher<Sha256>>(msg);
+        assert_eq!(p, r.into_affine().mul_by_cofactor_to_group());
+        assert_eq!(p, hasher.hash(msg));
+
+        let p = TEAffine::<P>::from(p);
+        assert!(p.is_on_curve());
+        assert!(p.is_in_correct_subgroup_assuming_on_curve());
+        assert!(!p.is_zero());
+
+        let p = Elligator2HashToCurve::<M>::encode::<XmdFieldHasher<Sha256>>(msg);
+        assert_eq!(p, hasher.encode(msg));
+        assert!(p.into_affine().is_in_correct_subgroup_assuming_on_curve());
+    }
+    assert!(out_of_subgroup > 0);
+
+    let points: Vec<TEAffine<P>> = MSGS.iter().map(|msg| hasher.hash(msg).into()).collect();
+    assert_eq!(hasher.hash_batch(&MSGS), points);
+}
+
+/// Check the Elligator 2 map `M` onto `P` against the pinned outputs of hashing to curve
+/// with the default tag `M::DST`
+fn check_ell2_ro<P, M>(vectors: &[RoTestVector])
+where
+    P: TECurveConfig,
+    P::BaseField: PrimeField,
+    M: Elligator2Map<P>,
+{
+    for v in vectors {
+        let u: Vec<P::BaseField> = Elligator2HashToCurve::<M>::hash_to_field::<
+            XmdFieldHasher<Sha256>,
+        >(v.msg.as_bytes(), M::DST, 2);
+        assert_eq!(u[0], field_from_hex(v.u[0]));
+        assert_eq!(u[1], field_from_hex(v.u[1]));
+
+        assert_eq!(M::map_to_curve(&u[0]), te_from_hex(&v.q0));
+        assert_eq!(M::map_to_curve(&u[1]), te_from_hex(&v.q1));
+
+        let p = Elligator2HashToCurve::<M>::hash::<XmdFieldHasher<Sha256>>(v.msg.as_bytes());
+        assert_eq!(p.into_affine(), te_from_hex(&v.p));
+    }
+}
+
+/// Check the Elligator 2 map `M` onto `P` against the pinned outputs of encoding to curve
+/// with the default tag `M::DST`
+fn check_ell2_nu<P, M>(vectors: &[NuTestVector])
+where
+    P: TECurveConfig,
+    P::BaseField: PrimeField,
+    M: Elligator2Map<P>,
+{
+    for v in vectors {
+        let u: Vec<P::BaseField> = Elligator2HashToCurve::<M>::hash_to_field::<
+            XmdFieldHasher<Sha256>,
+        >(v.msg.as_bytes(), M::DST, 1);
+        assert_eq!(u[0], field_from_hex(v.u));
+        assert_eq!(M::map_to_curve(&u[0]), te_from_hex(&v.q));
+
+        let p = Elligator2HashToCurve::<M>::encode::<XmdFieldHasher<Sha256>>(v.msg.as_bytes());
+        assert_eq!(p.into_affine(), te_from_hex(&v.p));
+    }
+}
+
+#[test]
+fn test_jubjub_xmd_sha256_ell2() {
+    check_ell2::<JubjubConfig, JubjubEll2Map>();
+    check_ell2_ro::<JubjubConfig, JubjubEll2Map>(&JUBJUB_XMD_SHA256_ELL2_RO);
+    check_ell2_nu::<JubjubConfig, JubjubEll2Map>(&JUBJUB_XMD_SHA256_ELL2_NU);
+
+    let dst = JubjubEll2Map::DST;
+    assert_eq!(
+        Elligator2HashToCurve::<JubjubEll2Map>::hash_to_curve(b"abc", dst).into_affine(),
+        te_from_hex(&JUBJUB_XMD_SHA256_ELL2_RO[1].p)
+    );
+    assert_eq!(
+        Elligator2HashToCurve::<JubjubEll2Map>::encode_to_curve(b"abc", dst).into_affine(),
+        te_from_hex(&JUBJUB_XMD_SHA256_ELL2_NU[1].p)
+    );
+}
+
+#[test]
+fn test_bandersnatch_xmd_sha256_ell2() {
+    check_ell2::<BandersnatchConfig, BandersnatchEll2Map>();
+    check_ell2_ro::<BandersnatchConfig, BandersnatchEll2Map>(&BANDERSNATCH_XMD_SHA256_ELL2_RO);
+    check_ell2_nu::<BandersnatchConfig, BandersnatchEll2Map>(&BANDERSNATCH_XMD_SHA256_ELL2_NU);
+
+    let dst = BandersnatchEll2Map::DST;
+    assert_eq!(
+        Elligator2HashToCurve::<BandersnatchEll2Map>::hash_to_curve(b"abc", dst).into_affine(),
+        te_from_hex(&BANDERSNATCH_XMD_SHA256_ELL2_RO[1].p)
+    );
+    assert_eq!(
+        Elligator2HashToCurve::<BandersnatchEll2Map>::encode_to_curve(b"abc", dst).into_affine(),
+        te_from_hex(&BANDERSNATCH_XMD_SHA256_ELL2_NU[1].p)
+    );
+}
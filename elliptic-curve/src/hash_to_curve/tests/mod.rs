@@ -0,0 +1,881 @@
+use ark_ec::short_weierstrass::{Affine as SWAffine, Projective as SWProjective, SWCurveConfig};
+use ark_ff::field_hashers::HashToField;
+use ark_ff::MontFp;
+use ark_ff::PrimeField;
+use ark_ff::{FftField, Field, Fp2, Fp2Config, Zero};
+use ark_secp256k1::{Affine, Config, Fq, Fr};
+use ark_std::ops::{Mul, Neg};
+use ark_std::rand::Rng;
+use ark_std::test_rng;
+use ark_std::UniformRand;
+use sha2::Sha256;
+use sha3::{Shake128, Shake256};
+
+use crate::hash_to_curve::constant_time::{
+    inv0, is_square, sqrt_ratio, sswu, sswu_3mod4, ConstantTime,
+};
+use crate::hash_to_curve::expander::{Expander, ExpanderXmd, ExpanderXof};
+use crate::hash_to_curve::field_hasher::{XmdFieldHasher, XofFieldHasher};
+use crate::hash_to_curve::hasher::{HashToCurve, Hasher};
+use crate::hash_to_curve::optimized_swu::secp256K1_oswu::Secp256K1OSWUMap;
+use crate::hash_to_curve::optimized_swu::{OptimizedSWUHashToCurve, OptimizedSWUMap};
+use crate::hash_to_curve::simplified_swu::secp256K1_sswu::Secp256K1SSWUMap;
+use crate::hash_to_curve::simplified_swu::{SimplifiedSWUHashToCurve, SimplifiedSWUMap};
+use crate::hash_to_curve::sw_map::secp256K1_sw::Secp256K1SWMap;
+use crate::hash_to_curve::sw_map::{SWHashToCurve, SWMap};
+
+mod arkworks;
+mod bls12381_g1;
+mod bls12381_g2;
+mod bn254;
+mod curve25519;
+mod ellswift;
+mod jubjub;
+mod p256;
+mod p384;
+mod p521;
+mod pasta;
+
+/// A test vector of a random oracle suite, see Appendix J of RFC 9380
+struct RoTestVector {
+    msg: &'static str,
+    p: [&'static str; 2],
+    u: [&'static str; 2],
+    q0: [&'static str; 2],
+    q1: [&'static str; 2],
+}
+
+/// A test vector of a nonuniform encoding suite, see Appendix J of RFC 9380
+struct NuTestVector {
+    msg: &'static str,
+    p: [&'static str; 2],
+    u: &'static str,
+    q: [&'static str; 2],
+}
+
+/// A test vector of a random oracle suite over Fp2,
+/// an element of Fp2 is [c0, c1] for c0 + c1 * I and a point is [x, y]
+struct G2RoTestVector {
+    msg: &'static str,
+    p: [[&'static str; 2]; 2],
+    u: [[&'static str; 2]; 2],
+    q0: [[&'static str; 2]; 2],
+    q1: [[&'static str; 2]; 2],
+}
+
+/// A test vector of a nonuniform encoding suite over Fp2
+struct G2NuTestVector {
+    msg: &'static str,
+    p: [[&'static str; 2]; 2],
+    u: [&'static str; 2],
+    q: [[&'static str; 2]; 2],
+}
+
+fn field_from_hex<F: PrimeField>(s: &str) -> F {
+    F::from_be_bytes_mod_order(&hex::decode(s).unwrap())
+}
+
+fn affine_from_hex<P: SWCurveConfig>(p: &[&str; 2]) -> SWAffine<P>
+where
+    P::BaseField: PrimeField,
+{
+    // the points before clearing the cofactor are not in the prime order subgroup
+    let point = SWAffine::<P>::new_unchecked(field_from_hex(p[0]), field_from_hex(p[1]));
+    assert!(point.is_on_curve());
+    point
+}
+
+fn fq2_from_hex<P: Fp2Config>(e: &[&str; 2]) -> Fp2<P> {
+    Fp2::new(field_from_hex(e[0]), field_from_hex(e[1]))
+}
+
+fn g2_from_hex<C: Fp2Config, P: SWCurveConfig<BaseField = Fp2<C>>>(
+    p: &[[&str; 2]; 2],
+) -> SWAffine<P> {
+    let point = SWAffine::<P>::new_unchecked(fq2_from_hex(&p[0]), fq2_from_hex(&p[1]));
+    assert!(point.is_on_curve());
+    point
+}
+
+/// Check a random oracle suite with both the Simplified SWU map `S` and the Optimized SWU map `O`
+fn check_sswu_ro<P, S, O, H>(dst: &[u8], vectors: &[RoTestVector])
+where
+    P: SWCurveConfig,
+    P::BaseField: PrimeField,
+    S: SimplifiedSWUMap<P>,
+    O: OptimizedSWUMap<P>,
+    H: HashToField<P::BaseField> + Sync,
+{
+    for v in vectors {
+        let msg = v.msg.as_bytes();
+        let u: Vec<P::BaseField> = SimplifiedSWUHashToCurve::<S>::hash_to_field::<H>(msg, dst, 2);
+        assert_eq!(u, vec![field_from_hex(v.u[0]), field_from_hex(v.u[1])]);
+
+        let q0: SWAffine<P> = affine_from_hex(&v.q0);
+        let q1: SWAffine<P> = affine_from_hex(&v.q1);
+        let p: SWAffine<P> = affine_from_hex(&v.p);
+
+        assert_eq!(S::map_to_curve(&u[0]), q0);
+        assert_eq!(S::map_to_curve(&u[1]), q1);
+        assert_eq!(SWAffine::from(O::map_to_curve(&u[0])), q0);
+        assert_eq!(SWAffine::from(O::map_to_curve(&u[1])), q1);
+
+        assert_eq!(
+            SWAffine::from(SimplifiedSWUHashToCurve::<S>::hash_with_dst::<H>(msg, dst)),
+            p
+        );
+        assert_eq!(
+            SWAffine::from(OptimizedSWUHashToCurve::<O>::hash_with_dst::<H>(msg, dst)),
+            p
+        );
+    }
+
+    // the batch shares the inversions among all the messages
+    let msgs: Vec<&[u8]> = vectors.iter().map(|v| v.msg.as_bytes()).collect();
+    let ps: Vec<SWAffine<P>> = vectors.iter().map(|v| affine_from_hex(&v.p)).collect();
+    assert_eq!(
+        SimplifiedSWUHashToCurve::<S>::hash_batch_with_dst::<H>(&msgs, dst),
+        ps
+    );
+    assert_eq!(
+        OptimizedSWUHashToCurve::<O>::hash_batch_with_dst::<H>(&msgs, dst),
+        ps
+    );
+    assert_eq!(
+        Hasher::<SWProjective<P>, SimplifiedSWUHashToCurve<S>, H>::new(dst).hash_batch(&msgs),
+        ps
+    );
+}
+
+/// Check a nonuniform encoding suite with both the Simplified SWU map `S` and the Optimized SWU map `O`
+fn check_sswu_nu<P, S, O, H>(dst: &[u8], vectors: &[NuTestVector])
+where
+    P: SWCurveConfig,
+    P::BaseField: PrimeField,
+    S: SimplifiedSWUMap<P>,
+    O: OptimizedSWUMap<P>,
+    H: HashToField<P::BaseField>,
+{
+    for v in vectors {
+        let msg = v.msg.as_bytes();
+        let u: Vec<P::BaseField> = SimplifiedSWUHashToCurve::<S>::hash_to_field::<H>(msg, dst, 1);
+        assert_eq!(u, vec![field_from_hex(v.u)]);
+
+        let q: SWAffine<P> = affine_from_hex(&v.q);
+        let p: SWAffine<P> = affine_from_hex(&v.p);
+
+        assert_eq!(S::map_to_curve(&u[0]), q);
+        assert_eq!(SWAffine::from(O::map_to_curve(&u[0])), q);
+
+        assert_eq!(
+            SWAffine::from(SimplifiedSWUHashToCurve::<S>::encode_with_dst::<H>(
+                msg, dst
+            )),
+            p
+        );
+        assert_eq!(
+            SWAffine::from(OptimizedSWUHashToCurve::<O>::encode_with_dst::<H>(msg, dst)),
+            p
+        );
+    }
+}
+
+const SECP256K1_XMD_SHA256_SSWU_RO_DST: &[u8] =
+    b"QUUX-V01-CS02-with-secp256k1_XMD:SHA-256_SSWU_RO_";
+
+/// Appendix J.8.1 of RFC 9380
+const SECP256K1_XMD_SHA256_SSWU_RO: [RoTestVector; 5] = [
+    RoTestVector {
+        msg: "",
+        p: [
+            "c1cae290e291aee617ebaef1be6d73861479c48b841eaba9b7b5852ddfeb1346",
+            "64fa678e07ae116126f08b022a94af6de15985c996c3a91b64c406a960e51067",
+        ],
+        u: [
+            "6b0f9910dd2ba71c78f2ee9f04d73b5f4c5f7fc773a701abea1e573cab002fb3",
+            "1ae6c212e08fe1a5937f6202f929a2cc8ef4ee5b9782db68b0d5799fd8f09e16",
+        ],
+        q0: [
+            "74519ef88b32b425a095e4ebcc84d81b64e9e2c2675340a720bb1a1857b99f1e",
+            "c174fa322ab7c192e11748beed45b508e9fdb1ce046dee9c2cd3a2a86b410936",
+        ],
+        q1: [
+            "44548adb1b399263ded3510554d28b4bead34b8cf9a37b4bd0bd2ba4db87ae63",
+            "96eb8e2faf05e368efe5957c6167001760233e6dd2487516b46ae725c4cce0c6",
+        ],
+    },
+    RoTestVector {
+        msg: "abc",
+        p: [
+            "3377e01eab42db296b512293120c6cee72b6ecf9f9205760bd9ff11fb3cb2c4b",
+            "7f95890f33efebd1044d382a01b1bee0900fb6116f94688d487c6c7b9c8371f6",
+        ],
+        u: [
+            "128aab5d3679a1f7601e3bdf94ced1f43e491f544767e18a4873f397b08a2b61",
+            "5897b65da3b595a813d0fdcc75c895dc531be76a03518b044daaa0f2e4689e00",
+        ],
+        q0: [
+            "07dd9432d426845fb19857d1b3a91722436604ccbbbadad8523b8fc38a5322d7",
+            "604588ef5138cffe3277bbd590b8550bcbe0e523bbaf1bed4014a467122eb33f",
+        ],
+        q1: [
+            "e9ef9794d15d4e77dde751e06c182782046b8dac05f8491eb88764fc65321f78",
+            "cb07ce53670d5314bf236ee2c871455c562dd76314aa41f012919fe8e7f717b3",
+        ],
+    },
+    RoTestVector {
+        msg: "abcdef0123456789",
+        p: [
+            "bac54083f293f1fe08e4a70137260aa90783a5cb84d3f35848b324d0674b0e3a",
+            "4436476085d4c3c4508b60fcf4389c40176adce756b398bdee27bca19758d828",
+        ],
+        u: [
+            "ea67a7c02f2cd5d8b87715c169d055a22520f74daeb080e6180958380e2f98b9",
+            "7434d0d1a500d38380d1f9615c021857ac8d546925f5f2355319d823a478da18",
+        ],
+        q0: [
+            "576d43ab0260275adf11af990d130a5752704f79478628761720808862544b5d",
+            "643c4a7fb68ae6cff55edd66b809087434bbaff0c07f3f9ec4d49bb3c16623c3",
+        ],
+        q1: [
+            "f89d6d261a5e00fe5cf45e827b507643e67c2a947a20fd9ad71039f8b0e29ff8",
+            "b33855e0cc34a9176ead91c6c3acb1aacb1ce936d563bc1cee1dcffc806caf57",
+        ],
+    },
+    RoTestVector {
+        msg: "q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq",
+        p: [
+            "e2167bc785333a37aa562f021f1e881defb853839babf52a7f72b102e41890e9",
+            "f2401dd95cc35867ffed4f367cd564763719fbc6a53e969fb8496a1e6685d873",
+        ],
+        u: [
+            "eda89a5024fac0a8207a87e8cc4e85aa3bce10745d501a30deb87341b05bcdf5",
+            "dfe78cd116818fc2c16f3837fedbe2639fab012c407eac9dfe9245bf650ac51d",
+        ],
+        q0: [
+            "9c91513ccfe9520c9c645588dff5f9b4e92eaf6ad4ab6f1cd720d192eb58247a",
+            "c7371dcd0134412f221e386f8d68f49e7fa36f9037676e163d4a063fbf8a1fb8",
+        ],
+        q1: [
+            "10fee3284d7be6bd5912503b972fc52bf4761f47141a0015f1c6ae36848d869b",
+            "0b163d9b4bf21887364332be3eff3c870fa053cf508732900fc69a6eb0e1b672",
+        ],
+    },
+    RoTestVector {
+        msg: "a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+        p: [
+            "e3c8d35aaaf0b9b647e88a0a0a7ee5d5bed5ad38238152e4e6fd8c1f8cb7c998",
+            "8446eeb6181bf12f56a9d24e262221cc2f0c4725c7e3803024b5888ee5823aa6",
+        ],
+        u: [
+            "8d862e7e7e23d7843fe16d811d46d7e6480127a6b78838c277bca17df6900e9f",
+            "68071d2530f040f081ba818d3c7188a94c900586761e9115efa47ae9bd847938",
+        ],
+        q0: [
+            "b32b0ab55977b936f1e93fdc68cec775e13245e161dbfe556bbb1f72799b4181",
+            "2f5317098360b722f132d7156a94822641b615c91f8663be69169870a12af9e8",
+        ],
+        q1: [
+            "148f98780f19388b9fa93e7dc567b5a673e5fca7079cd9cdafd71982ec4c5e12",
+            "3989645d83a433bc0c001f3dac29af861f33a6fd1e04f4b36873f5bff497298a",
+        ],
+    },
+];
+
+const SECP256K1_XMD_SHA256_SSWU_NU_DST: &[u8] =
+    b"QUUX-V01-CS02-with-secp256k1_XMD:SHA-256_SSWU_NU_";
+
+/// Appendix J.8.2 of RFC 9380
+const SECP256K1_XMD_SHA256_SSWU_NU: [NuTestVector; 5] = [
+    NuTestVector {
+        msg: "",
+        p: [
+            "a4792346075feae77ac3b30026f99c1441b4ecf666ded19b7522cf65c4c55c5b",
+            "62c59e2a6aeed1b23be5883e833912b08ba06be7f57c0e9cdc663f31639ff3a7",
+        ],
+        u: "0137fcd23bc3da962e8808f97474d097a6c8aa2881fceef4514173635872cf3b",
+        q: [
+            "a4792346075feae77ac3b30026f99c1441b4ecf666ded19b7522cf65c4c55c5b",
+            "62c59e2a6aeed1b23be5883e833912b08ba06be7f57c0e9cdc663f31639ff3a7",
+        ],
+    },
+    NuTestVector {
+        msg: "abc",
+        p: [
+            "3f3b5842033fff837d504bb4ce2a372bfeadbdbd84a1d2b678b6e1d7ee426b9d",
+            "902910d1fef15d8ae2006fc84f2a5a7bda0e0407dc913062c3a493c4f5d876a5",
+        ],
+        u: "e03f894b4d7caf1a50d6aa45cac27412c8867a25489e32c5ddeb503229f63a2e",
+        q: [
+            "3f3b5842033fff837d504bb4ce2a372bfeadbdbd84a1d2b678b6e1d7ee426b9d",
+            "902910d1fef15d8ae2006fc84f2a5a7bda0e0407dc913062c3a493c4f5d876a5",
+        ],
+    },
+    NuTestVector {
+        msg: "abcdef0123456789",
+        p: [
+            "07644fa6281c694709f53bdd21bed94dab995671e4a8cd1904ec4aa50c59bfdf",
+            "c79f8d1dad79b6540426922f7fbc9579c3018dafeffcd4552b1626b506c21e7b",
+        ],
+        u: "e7a6525ae7069ff43498f7f508b41c57f80563c1fe4283510b322446f32af41b",
+        q: [
+            "07644fa6281c694709f53bdd21bed94dab995671e4a8cd1904ec4aa50c59bfdf",
+            "c79f8d1dad79b6540426922f7fbc9579c3018dafeffcd4552b1626b506c21e7b",
+        ],
+    },
+    NuTestVector {
+        msg: "q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq",
+        p: [
+            "b734f05e9b9709ab631d960fa26d669c4aeaea64ae62004b9d34f483aa9acc33",
+            "03fc8a4a5a78632e2eb4d8460d69ff33c1d72574b79a35e402e801f2d0b1d6ee",
+        ],
+        u: "d97cf3d176a2f26b9614a704d7d434739d194226a706c886c5c3c39806bc323c",
+        q: [
+            "b734f05e9b9709ab631d960fa26d669c4aeaea64ae62004b9d34f483aa9acc33",
+            "03fc8a4a5a78632e2eb4d8460d69ff33c1d72574b79a35e402e801f2d0b1d6ee",
+        ],
+    },
+    NuTestVector {
+        msg: "a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+        p: [
+            "17d22b867658977b5002dbe8d0ee70a8cfddec3eec50fb93f36136070fd9fa6c",
+            "e9178ff02f4dab73480f8dd590328aea99856a7b6cc8e5a6cdf289ecc2a51718",
+        ],
+        u: "a9ffbeee1d6e41ac33c248fb3364612ff591b502386c1bf6ac4aaf1ea51f8c3b",
+        q: [
+            "17d22b867658977b5002dbe8d0ee70a8cfddec3eec50fb93f36136070fd9fa6c",
+            "e9178ff02f4dab73480f8dd590328aea99856a7b6cc8e5a6cdf289ecc2a51718",
+        ],
+    },
+];
+
+#[test]
+fn test_expand_message_xmd_sha256() {
+    // Appendix K.1 of RFC 9380
+    let expander = ExpanderXmd::<Sha256>::new(b"QUUX-V01-CS02-with-expander-SHA256-128");
+
+    let uniform_bytes = expander.expand(b"", 0x20);
+    assert_eq!(
+        hex::encode(uniform_bytes),
+        "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235"
+    );
+
+    let uniform_bytes = expander.expand(b"abc", 0x20);
+    assert_eq!(
+        hex::encode(uniform_bytes),
+        "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615"
+    );
+
+    let uniform_bytes = expander.expand(b"abcdef0123456789", 0x80);
+    assert_eq!(
+        hex::encode(uniform_bytes),
+        "ef904a29bffc4cf9ee82832451c946ac3c8f8058ae97d8d629831a74c6572bd9\
+         ebd0df635cd1f208e2038e760c4994984ce73f0d55ea9f22af83ba4734569d4b\
+         c95e18350f740c07eef653cbb9f87910d833751825f0ebefa1abe5420bb52be1\
+         4cf489b37fe1a72f7de2d10be453b2c9d9eb20c7e3f6edc5a60629178d9478df"
+    );
+}
+
+#[test]
+fn test_expand_message_xof_shake128() {
+    // Appendix K.4 of RFC 9380
+    let expander = ExpanderXof::<Shake128>::new(b"QUUX-V01-CS02-with-expander-SHAKE128", 128);
+
+    let uniform_bytes = expander.expand(b"", 0x20);
+    assert_eq!(
+        hex::encode(uniform_bytes),
+        "86518c9cd86581486e9485aa74ab35ba150d1c75c88e26b7043e44e2acd735a2"
+    );
+
+    let uniform_bytes = expander.expand(b"abc", 0x20);
+    assert_eq!(
+        hex::encode(uniform_bytes),
+        "8696af52a4d862417c0763556073f47bc9b9ba43c99b505305cb1ec04a9ab468"
+    );
+
+    let uniform_bytes = expander.expand(b"abcdef0123456789", 0x80);
+    assert_eq!(
+        hex::encode(uniform_bytes),
+        "19b65ee7afec6ac06a144f2d6134f08eeec185f1a890fe34e68f0e377b7d0312\
+         883c048d9b8a1d6ecc3b541cb4987c26f45e0c82691ea299b5e6889bbfe58915\
+         3016d8131717ba26f07c3c14ffbef1f3eff9752e5b6183f43871a78219a75e70\
+         00fbac6a7072e2b83c790a3a5aecd9d14be79f9fd4fb180960a3772e08680495"
+    );
+}
+
+#[test]
+fn test_expand_message_xof_shake256() {
+    // Appendix K.5 of RFC 9380
+    let expander = ExpanderXof::<Shake256>::new(b"QUUX-V01-CS02-with-expander-SHAKE256", 256);
+
+    let uniform_bytes = expander.expand(b"", 0x20);
+    assert_eq!(
+        hex::encode(uniform_bytes),
+        "2ffc05c48ed32b95d72e807f6eab9f7530dd1c2f013914c8fed38c5ccc15ad76"
+    );
+
+    let uniform_bytes = expander.expand(b"abc", 0x20);
+    assert_eq!(
+        hex::encode(uniform_bytes),
+        "b39e493867e2767216792abce1f2676c197c0692aed061560ead251821808e07"
+    );
+
+    let uniform_bytes = expander.expand(b"abc", 0x80);
+    assert_eq!(
+        hex::encode(uniform_bytes),
+        "a54303e6b172909783353ab05ef08dd435a558c3197db0c132134649708e0b9b\
+         4e34fb99b92a9e9e28fc1f1d8860d85897a8e021e6382f3eea10577f968ff6df\
+         6c45fe624ce65ca25932f679a42a404bc3681efe03fcd45ef73bb3a8f79ba784\
+         f80f55ea8a3c367408f30381299617f50c8cf8fbb21d0f1e1d70b0131a7b6fbe"
+    );
+}
+
+/// Pad `prefix` with '1' up to 256 bytes, as the long domain separation tags of Appendix K
+fn long_dst(prefix: &str) -> Vec<u8> {
+    let mut dst = prefix.as_bytes().to_vec();
+    dst.resize(256, b'1');
+    dst
+}
+
+#[test]
+fn test_expand_message_with_long_dst() {
+    // Appendix K.2 of RFC 9380
+    let dst = long_dst("QUUX-V01-CS02-with-expander-SHA256-128-long-DST-");
+    let expander = ExpanderXmd::<Sha256>::new(&dst);
+
+    let uniform_bytes = expander.expand(b"", 0x20);
+    assert_eq!(
+        hex::encode(uniform_bytes),
+        "e8dc0c8b686b7ef2074086fbdd2f30e3f8bfbd3bdf177f73f04b97ce618a3ed3"
+    );
+
+    let uniform_bytes = expander.expand(b"abc", 0x20);
+    assert_eq!(
+        hex::encode(uniform_bytes),
+        "52dbf4f36cf560fca57dedec2ad924ee9c266341d8f3d6afe5171733b16bbb12"
+    );
+
+    // Appendix K.5 of RFC 9380
+    let dst = long_dst("QUUX-V01-CS02-with-expander-SHAKE128-long-DST-");
+    let expander = ExpanderXof::<Shake128>::new(&dst, 128);
+
+    let uniform_bytes = expander.expand(b"", 0x20);
+    assert_eq!(
+        hex::encode(uniform_bytes),
+        "827c6216330a122352312bccc0c8d6e7a146c5257a776dbd9ad9d75cd880fc53"
+    );
+
+    let uniform_bytes = expander.expand(b"abc", 0x20);
+    assert_eq!(
+        hex::encode(uniform_bytes),
+        "690c8d82c7213b4282c6cb41c00e31ea1d3e2005f93ad19bbf6da40f15790c5c"
+    );
+}
+
+#[test]
+fn test_xof_hash_to_field() {
+    let dst = b"QUUX-V01-CS02-with-secp256k1_XOF:SHAKE256_SSWU_RO_";
+    let msg = b"abc";
+
+    // L = ceil((256 + 256) / 8) = 64 bytes per field element
+    let expander = ExpanderXof::<Shake256>::new(dst, 256);
+    let uniform_bytes = expander.expand(msg, 2 * 64);
+    let u0 = Fq::from_be_bytes_mod_order(&uniform_bytes[..64]);
+    let u1 = Fq::from_be_bytes_mod_order(&uniform_bytes[64..]);
+
+    let u: Vec<Fq> = SimplifiedSWUHashToCurve::<Secp256K1SSWUMap>::hash_to_field::<
+        XofFieldHasher<Shake256, 256>,
+    >(msg, dst, 2);
+    assert_eq!(u, vec![u0, u1]);
+
+    let point = SimplifiedSWUHashToCurve::<Secp256K1SSWUMap>::hash_with_dst::<
+        XofFieldHasher<Shake256, 256>,
+    >(msg, dst);
+    assert!(Affine::from(point).is_on_curve());
+    assert_eq!(
+        point,
+        OptimizedSWUHashToCurve::<Secp256K1OSWUMap>::hash_with_dst::<XofFieldHasher<Shake256, 256>>(
+            msg, dst
+        )
+    );
+    assert_ne!(
+        point,
+        SimplifiedSWUHashToCurve::<Secp256K1SSWUMap>::hash_with_dst::<XmdFieldHasher<Sha256>>(
+            msg, dst
+        )
+    );
+
+    let point = SWHashToCurve::<Secp256K1SWMap>::encode::<XofFieldHasher<Shake128>>(msg);
+    assert!(Affine::from(point).is_on_curve());
+}
+
+#[test]
+fn test_secp256k1_xmd_sha256_sswu_ro() {
+    check_sswu_ro::<Config, Secp256K1SSWUMap, Secp256K1OSWUMap, XmdFieldHasher<Sha256>>(
+        SECP256K1_XMD_SHA256_SSWU_RO_DST,
+        &SECP256K1_XMD_SHA256_SSWU_RO,
+    );
+
+    for v in SECP256K1_XMD_SHA256_SSWU_RO.iter() {
+        let msg = v.msg.as_bytes();
+        let dst = SECP256K1_XMD_SHA256_SSWU_RO_DST;
+        let p: Affine = affine_from_hex(&v.p);
+        assert_eq!(
+            Affine::from(SimplifiedSWUHashToCurve::<Secp256K1SSWUMap>::hash_to_curve(
+                msg, dst
+            )),
+            p
+        );
+        assert_eq!(
+            Affine::from(OptimizedSWUHashToCurve::<Secp256K1OSWUMap>::hash_to_curve(
+                msg, dst
+            )),
+            p
+        );
+    }
+}
+
+#[test]
+fn test_secp256k1_xmd_sha256_sswu_nu() {
+    check_sswu_nu::<Config, Secp256K1SSWUMap, Secp256K1OSWUMap, XmdFieldHasher<Sha256>>(
+        SECP256K1_XMD_SHA256_SSWU_NU_DST,
+        &SECP256K1_XMD_SHA256_SSWU_NU,
+    );
+
+    for v in SECP256K1_XMD_SHA256_SSWU_NU.iter() {
+        let msg = v.msg.as_bytes();
+        let dst = SECP256K1_XMD_SHA256_SSWU_NU_DST;
+        let p: Affine = affine_from_hex(&v.p);
+        assert_eq!(
+            Affine::from(SimplifiedSWUHashToCurve::<Secp256K1SSWUMap>::encode_to_curve(msg, dst)),
+            p
+        );
+        assert_eq!(
+            Affine::from(OptimizedSWUHashToCurve::<Secp256K1OSWUMap>::encode_to_curve(msg, dst)),
+            p
+        );
+    }
+}
+
+#[test]
+fn test_hasher_with_runtime_dst() {
+    let hasher = Hasher::<
+        SWProjective<Config>,
+        SimplifiedSWUHashToCurve<Secp256K1SSWUMap>,
+        XmdFieldHasher<Sha256>,
+    >::new(SECP256K1_XMD_SHA256_SSWU_RO_DST);
+    for v in SECP256K1_XMD_SHA256_SSWU_RO {
+        assert_eq!(
+            Affine::from(hasher.hash(v.msg.as_bytes())),
+            affine_from_hex(&v.p)
+        );
+    }
+
+    let hasher = Hasher::<
+        SWProjective<Config>,
+        OptimizedSWUHashToCurve<Secp256K1OSWUMap>,
+        XmdFieldHasher<Sha256>,
+    >::new(SECP256K1_XMD_SHA256_SSWU_NU_DST);
+    for v in SECP256K1_XMD_SHA256_SSWU_NU {
+        assert_eq!(
+            Affine::from(hasher.encode(v.msg.as_bytes())),
+            affine_from_hex(&v.p)
+        );
+    }
+
+    let msg = b"hello, hash to secp256k1 ";
+    let hasher =
+        Hasher::<SWProjective<Config>, SWHashToCurve<Secp256K1SWMap>, XmdFieldHasher<Sha256>>::new(
+            b"my-protocol-v1",
+        );
+    let point = hasher.hash(msg);
+    assert!(Affine::from(point).is_on_curve());
+    assert_eq!(
+        point,
+        SWHashToCurve::<Secp256K1SWMap>::hash_with_dst::<XmdFieldHasher<Sha256>>(
+            msg,
+            b"my-protocol-v1"
+        )
+    );
+    assert_ne!(
+        point,
+        SWHashToCurve::<Secp256K1SWMap>::hash::<XmdFieldHasher<Sha256>>(msg)
+    );
+
+    // a tag longer than 255 bytes is accepted and hashed to a short one
+    let dst = long_dst("my-protocol-v1-");
+    let hasher = Hasher::<
+        SWProjective<Config>,
+        SWHashToCurve<Secp256K1SWMap>,
+        XofFieldHasher<Shake128>,
+    >::new(&dst);
+    let point = hasher.encode(msg);
+    assert!(Affine::from(point).is_on_curve());
+    assert_eq!(
+        point,
+        SWHashToCurve::<Secp256K1SWMap>::encode_with_dst::<XofFieldHasher<Shake128>>(msg, &dst)
+    );
+}
+
+#[test]
+fn test_exceptional_inputs_for_secp256k1() {
+    // u = 0 and u^2 = -1 / Z make Z^2 * u^4 + Z * u^2 zero, then x1 = B / (Z * A)
+    // -1 / Z = 1 / 11 is square modulo p of secp256k1
+    let u = Secp256K1SSWUMap::Z
+        .inverse()
+        .unwrap()
+        .neg()
+        .sqrt()
+        .expect("-1 / Z should be square for secp256k1");
+    for u in [Fq::zero(), u, -u] {
+        let point = Secp256K1SSWUMap::map_to_curve(&u);
+        assert!(point.is_on_curve());
+        assert_eq!(point, Secp256K1OSWUMap::map_to_curve(&u));
+    }
+
+    // u^2 * c1 = 1 or u^2 * c1 = -1 make the denominator of the SW map zero, then x1 = x2 = c2 and x3 = Z
+    let c1_inv = Secp256K1SWMap::c1().inverse().unwrap();
+    let mut exceptional_inputs = vec![Fq::zero()];
+    exceptional_inputs.extend(c1_inv.sqrt());
+    exceptional_inputs.extend(c1_inv.neg().sqrt());
+    assert!(exceptional_inputs.len() > 1);
+    for u in exceptional_inputs {
+        let point = Secp256K1SWMap::map_to_curve(&u);
+        assert!(point.is_on_curve());
+        if !u.is_zero() {
+            assert!(point.x == Secp256K1SWMap::c2() || point.x == Secp256K1SWMap::Z);
+        }
+    }
+}
+
+#[test]
+fn test_map_to_curve_batch_for_secp256k1() {
+    let mut rng = test_rng();
+    let mut us: Vec<Fq> = (0..100).map(|_| Fq::rand(&mut rng)).collect();
+
+    // the exceptional inputs of both maps are inverted to zero in the batch as well
+    us.push(Fq::zero());
+    us.extend(Secp256K1SSWUMap::Z.inverse().unwrap().neg().sqrt());
+    us.extend(Secp256K1SWMap::c1().inverse().unwrap().sqrt());
+    us.extend(Secp256K1SWMap::c1().inverse().unwrap().neg().sqrt());
+
+    let points: Vec<Affine> = us.iter().map(Secp256K1SWMap::map_to_curve).collect();
+    assert_eq!(Secp256K1SWMap::map_to_curve_batch(&us), points);
+    assert_eq!(
+        ConstantTime::<Secp256K1SWMap>::map_to_curve_batch(&us),
+        points
+    );
+
+    let points: Vec<Affine> = us.iter().map(Secp256K1SSWUMap::map_to_curve).collect();
+    assert_eq!(Secp256K1SSWUMap::map_to_curve_batch(&us), points);
+    assert_eq!(
+        ConstantTime::<Secp256K1SSWUMap>::map_to_curve_batch(&us),
+        points
+    );
+    assert_eq!(
+        OptimizedSWUHashToCurve::<Secp256K1OSWUMap>::map_to_curve_batch(&us),
+        points
+    );
+    assert!(Secp256K1SSWUMap::map_to_curve_batch(&[]).is_empty());
+}
+
+#[test]
+fn test_hash_batch_for_secp256k1() {
+    let mut rng = test_rng();
+    let msgs: Vec<Vec<u8>> = (0..20)
+        .map(|i| (0..i * 10).map(|_| rng.gen()).collect())
+        .collect();
+    let msgs: Vec<&[u8]> = msgs.iter().map(|msg| msg.as_slice()).collect();
+
+    let points: Vec<Affine> = msgs
+        .iter()
+        .map(|msg| SWHashToCurve::<Secp256K1SWMap>::hash::<XmdFieldHasher<Sha256>>(msg).into())
+        .collect();
+    assert_eq!(
+        SWHashToCurve::<Secp256K1SWMap>::hash_batch::<XmdFieldHasher<Sha256>>(&msgs),
+        points
+    );
+    assert_eq!(
+        SWHashToCurve::<ConstantTime<Secp256K1SWMap>>::hash_batch::<XmdFieldHasher<Sha256>>(&msgs),
+        points
+    );
+
+    let hasher =
+        Hasher::<SWProjective<Config>, SWHashToCurve<Secp256K1SWMap>, XmdFieldHasher<Sha256>>::new(
+            b"my-protocol-v1",
+        );
+    let points: Vec<Affine> = msgs.iter().map(|msg| hasher.hash(msg).into()).collect();
+    assert_eq!(hasher.hash_batch(&msgs), points);
+    assert!(hasher.hash_batch(&[]).is_empty());
+}
+
+#[test]
+fn test_sqrt_ratio() {
+    fn check_sqrt_ratio<F: PrimeField>(z: F) {
+        let mut rng = test_rng();
+        for _ in 0..100 {
+            let u = F::rand(&mut rng);
+            let v = F::rand(&mut rng);
+            let (is_qr, r) = sqrt_ratio(&u, &v, &z);
+            assert_eq!(is_qr, is_square(&(u / v)));
+            if is_qr {
+                assert_eq!(r.square() * v, u);
+            } else {
+                assert_eq!(r.square() * v, z * u);
+            }
+        }
+
+        // sqrt(0 / v) = sqrt(z * 0 / v) = 0
+        let (_, r) = sqrt_ratio(&F::zero(), &F::rand(&mut rng), &z);
+        assert!(r.is_zero());
+    }
+
+    // p = 3 mod 4 for the base field of secp256k1, and 2^6 divides n - 1 for the scalar field
+    check_sqrt_ratio::<Fq>(Secp256K1SSWUMap::Z);
+    check_sqrt_ratio::<Fr>(Fr::GENERATOR);
+
+    assert!(inv0(&Fq::zero()).is_zero());
+    assert_eq!(inv0(&Fq::from(11u64)), Fq::from(11u64).inverse().unwrap());
+    assert!(is_square(&Fq::zero()));
+    assert!(!is_square(&Secp256K1SSWUMap::Z));
+}
+
+#[test]
+fn test_sswu_3mod4() {
+    let a = Secp256K1OSWUMap::A;
+    let b = Secp256K1OSWUMap::B;
+    let z = Secp256K1OSWUMap::Z;
+    let c2 = Secp256K1OSWUMap::c2();
+    assert_eq!(c2.square(), z.square().mul(&z).neg());
+
+    let mut rng = test_rng();
+    let mut inputs: Vec<Fq> = (0..100).map(|_| Fq::rand(&mut rng)).collect();
+    inputs.push(Fq::zero());
+    for u in inputs {
+        let (x_num, x_den, y) = sswu_3mod4(&u, &a, &b, &z, &c2);
+        let (expect_x_num, expect_x_den, expect_y) = sswu(&u, &a, &b, &z);
+        assert_eq!(x_num / x_den, expect_x_num / expect_x_den);
+        assert_eq!(y, expect_y);
+    }
+}
+
+#[test]
+fn test_constant_time_maps_for_secp256k1() {
+    let mut rng = test_rng();
+    let mut inputs: Vec<Fq> = (0..100).map(|_| Fq::rand(&mut rng)).collect();
+    inputs.push(Fq::zero());
+    inputs.extend(Secp256K1SWMap::c1().inverse().unwrap().sqrt());
+    inputs.extend(Secp256K1SWMap::c1().inverse().unwrap().neg().sqrt());
+
+    for u in inputs {
+        assert_eq!(
+            Secp256K1SWMap::map_to_curve_ct(&u),
+            Secp256K1SWMap::map_to_curve(&u)
+        );
+        assert_eq!(
+            Secp256K1SSWUMap::map_to_curve_ct(&u),
+            Secp256K1SSWUMap::map_to_curve(&u)
+        );
+        assert_eq!(
+            Secp256K1OSWUMap::map_to_curve_ct(&u),
+            Secp256K1OSWUMap::map_to_curve(&u)
+        );
+    }
+
+    for v in SECP256K1_XMD_SHA256_SSWU_RO {
+        let point = SimplifiedSWUHashToCurve::<ConstantTime<Secp256K1SSWUMap>>::hash_with_dst::<
+            XmdFieldHasher<Sha256>,
+        >(v.msg.as_bytes(), SECP256K1_XMD_SHA256_SSWU_RO_DST);
+        assert_eq!(Affine::from(point), affine_from_hex(&v.p));
+    }
+
+    let hasher = Hasher::<
+        SWProjective<Config>,
+        OptimizedSWUHashToCurve<ConstantTime<Secp256K1OSWUMap>>,
+        XmdFieldHasher<Sha256>,
+    >::new(SECP256K1_XMD_SHA256_SSWU_NU_DST);
+    for v in SECP256K1_XMD_SHA256_SSWU_NU {
+        assert_eq!(
+            Affine::from(hasher.encode(v.msg.as_bytes())),
+            affine_from_hex(&v.p)
+        );
+    }
+
+    let msg = b"hello, hash to secp256k1 ";
+    assert_eq!(
+        SWHashToCurve::<ConstantTime<Secp256K1SWMap>>::hash::<XmdFieldHasher<Sha256>>(msg),
+        SWHashToCurve::<Secp256K1SWMap>::hash::<XmdFieldHasher<Sha256>>(msg)
+    );
+}
+
+#[test]
+fn test_sw_map_for_secp256k1() {
+    let msg = b"hello, hash to secp256k1 ";
+    let point = SWHashToCurve::<Secp256K1SWMap>::hash::<XmdFieldHasher<Sha256>>(msg);
+    let point: Affine = point.into();
+    assert!(point.is_on_curve());
+
+    let expect_point = Affine::new_unchecked(
+        MontFp!("48021831145120378923861733833988221457791244966984620950425345532305474477373"),
+        MontFp!("26138244781004390843718528744725384967248430736902188110856608918886034627223"),
+    );
+    assert_eq!(point, expect_point);
+}
+
+#[test]
+fn test_sswu_map_for_secp256k1() {
+    let msg = b"hello, hash to secp256k1 ";
+    let point = SimplifiedSWUHashToCurve::<Secp256K1SSWUMap>::hash::<XmdFieldHasher<Sha256>>(msg);
+    let point: Affine = point.into();
+    assert!(point.is_on_curve());
+
+    let expect_point = Affine::new_unchecked(
+        MontFp!("85831596738777539910538785440765787558281623609308991517316672299271394926298"),
+        MontFp!("102972263603229747744887938881293233544759838807644927062149618502809059327214"),
+    );
+    assert_eq!(point, expect_point);
+}
+
+#[test]
+fn test_oswu_map_for_secp256k1() {
+    let mut rng = test_rng();
+    for _ in 0..1000 {
+        let msg: Vec<u8> = (0..100).map(|_| rng.gen()).collect();
+        let point =
+            OptimizedSWUHashToCurve::<Secp256K1OSWUMap>::hash::<XmdFieldHasher<Sha256>>(&msg);
+        let point: Affine = point.into();
+        assert!(point.is_on_curve());
+
+        let expect_point = SimplifiedSWUHashToCurve::<Secp256K1SSWUMap>::hash_with_dst::<
+            XmdFieldHasher<Sha256>,
+        >(&msg, b"secp256k1_oswu");
+        assert_eq!(point, expect_point);
+    }
+}
+
+#[test]
+fn test_encode_for_secp256k1() {
+    let mut rng = test_rng();
+    for _ in 0..100 {
+        let msg: Vec<u8> = (0..100).map(|_| rng.gen()).collect();
+
+        let u: Vec<Fq> = SWHashToCurve::<Secp256K1SWMap>::hash_to_field::<XmdFieldHasher<Sha256>>(
+            &msg,
+            Secp256K1SWMap::DST,
+            1,
+        );
+        let point: Affine =
+            SWHashToCurve::<Secp256K1SWMap>::encode::<XmdFieldHasher<Sha256>>(&msg).into();
+        assert!(point.is_on_curve());
+        assert_eq!(point, Secp256K1SWMap::map_to_curve(&u[0]));
+
+        let point = SimplifiedSWUHashToCurve::<Secp256K1SSWUMap>::encode_with_dst::<
+            XmdFieldHasher<Sha256>,
+        >(&msg, b"secp256k1_encode");
+        let expect_point = OptimizedSWUHashToCurve::<Secp256K1OSWUMap>::encode_with_dst::<
+            XmdFieldHasher<Sha256>,
+        >(&msg, b"secp256k1_encode");
+        assert!(Affine::from(point).is_on_curve());
+        assert_eq!(point, expect_point);
+    }
+}
@@ -0,0 +1,199 @@
+use ark_ec::short_weierstrass::{Affine as SWAffine, Projective as SWProjective};
+use ark_ff::Zero;
+use ark_pallas::PallasConfig;
+use ark_vesta::VestaConfig;
+use blake2::Blake2b512;
+
+use super::{affine_from_hex, field_from_hex};
+use crate::hash_to_curve::constant_time::ConstantTime;
+use crate::hash_to_curve::field_hasher::XmdFieldHasher;
+use crate::hash_to_curve::hasher::{HashToCurve, Hasher};
+use crate::hash_to_curve::optimized_swu::pallas_oswu::PallasOSWUMap;
+use crate::hash_to_curve::optimized_swu::vesta_oswu::VestaOSWUMap;
+use crate::hash_to_curve::optimized_swu::{OptimizedSWUHashToCurve, OptimizedSWUMap};
+
+/// A test vector of a random oracle suite without the outputs of map_to_curve
+struct PastaTestVector {
+    msg: &'static str,
+    p: [&'static str; 2],
+    u: [&'static str; 2],
+}
+
+const PALLAS_XMD_BLAKE2B_SSWU_RO_DST: &[u8] = b"z.cash:test-pallas_XMD:BLAKE2b_SSWU_RO_";
+
+/// The outputs of `pallas::Point::hash_to_curve("z.cash:test")` of the `pasta_curves` crate
+const PALLAS_XMD_BLAKE2B_SSWU_RO: [PastaTestVector; 4] = [
+    PastaTestVector {
+        msg: "",
+        p: [
+            "015897b5287bed6cc9f42612e73aeed8673c05c3baf6c2cb2dd215db24ea162c",
+            "0ff52bee88954f82f3fd836c380b3bcda18ce958259ff0ff2775f3b895403589",
+        ],
+        u: [
+            "3b4caae3f4bc7928eef6238c7e255f7c5c4ad67e527a1455750e70b5786e0d6c",
+            "2421d2dab6e1b3f4222f779fef15dbdcea262d103b4cfe2833ccba4c471d0a01",
+        ],
+    },
+    PastaTestVector {
+        msg: "abc",
+        p: [
+            "173938b694d215c8ad044f3c246ea6adc0f59b14a7c764a42badb5baa81b6113",
+            "1fe7c6d9c29651342961ad90ea9f604274e8efd1e034494d800cf013a040a79c",
+        ],
+        u: [
+            "38c9ded51fae7eb744044e2ab475fbdd8279c3a2d2be6ae913cd993fa17aa348",
+            "338d8d2a7bada70d245a92df50991e6fd7093493b37016930cae15379ba47b6f",
+        ],
+    },
+    PastaTestVector {
+        msg: "hello",
+        p: [
+            "3d7fc29fa52fb474092db9249d1d96b2541ff73e7b95d498df97e004d7abbf93",
+            "0e0982e82dc9c46261de940b284f55f312ab27d52eda49a6644698ac3a23e6bb",
+        ],
+        u: [
+            "0ed0baf024010d57bd78a917c4566729b69331c0e0b5acc2b8664f6c7e019896",
+            "2a523d5561966ea329a3c21dd16899447fde3405d1b0dd9f71504dcfbf6e114b",
+        ],
+    },
+    PastaTestVector {
+        msg: "Trans rights now!",
+        p: [
+            "1818cda31ffdc8c3ff23df3d88c26f952340257d0f187a0236695c9b640b6bd3",
+            "01e20888510123752166a0306332e126289f6f9a2774160395f2f1efc9b1280c",
+        ],
+        u: [
+            "1bdd4c3fc1169a6d8eb82d66652f44a1e4a73cc1b6da4bba1d95fa6111c85a6f",
+            "0dd7332b3108010636107798c0ea89f94c79fb0472cb7b8222c450142802e4af",
+        ],
+    },
+];
+
+const VESTA_XMD_BLAKE2B_SSWU_RO_DST: &[u8] = b"z.cash:test-vesta_XMD:BLAKE2b_SSWU_RO_";
+
+/// The outputs of `vesta::Point::hash_to_curve("z.cash:test")` of the `pasta_curves` crate
+const VESTA_XMD_BLAKE2B_SSWU_RO: [PastaTestVector; 4] = [
+    PastaTestVector {
+        msg: "",
+        p: [
+            "0125c4dd7127efebc7dfaeafdedf0dae160543c2f5d7b0529cbe15a7a05749c2",
+            "296c814d449475efe14c9482465c963cdce2b45465f06068d6d6597904df4817",
+        ],
+        u: [
+            "04f6a727fd598352af3e54a0bf238a25df59f5ee0bd07c2fa146a790dfa095a9",
+            "35eb5628b0c1b7e74e0dfa2e74b489806c22b5548e39964a130a37cfee140a4c",
+        ],
+    },
+    PastaTestVector {
+        msg: "abc",
+        p: [
+            "119937fec645cbe784db7b81aa7886abcd5da638d9348228ecb5c2526a47863d",
+            "311ef415188f700f3b63c7fc2ee321320d0f5fa251fe9ce945a801ee527f4d9c",
+        ],
+        u: [
+            "049eb3a1697fa905f03acc7f3d8baf8afbbe5352f400de9f5a103473aff33a42",
+            "0d6db3917d387185ac4b074f782ea9ce7b30f2c6c6785972ff3a474a257554ea",
+        ],
+    },
+    PastaTestVector {
+        msg: "hello",
+        p: [
+            "2e983e009cf3b86bc95f91b3411bd6cbd0a87f8c3c3dae80f3f2637084849204",
+            "310fb8f3316d069a1fb9374bdbc0fb1391c864a5208b2a812341db7f50b2e106",
+        ],
+        u: [
+            "02ff3bc53fd8e95662b4614d32237aef43b36e53774401004eac13537507b1ac",
+            "249ed75088f240d4c420e893e3b9cebfeb151a2a6e3e3f7dad559a98f139fcef",
+        ],
+    },
+    PastaTestVector {
+        msg: "Trans rights now!",
+        p: [
+            "29f96e1924d14724cde553caa78f0cddb19b76ebdf4df7c88d4502827a31d587",
+            "33bffb1adebd8759f4551b8ffed3c0074d624392c1c5faebe5eda77bbda84342",
+        ],
+        u: [
+            "36a2ca3c9bab173bae7230a38b3cf91d8b491523c85f33854511746c5cb0d963",
+            "3d5eb2213dbd3b6a6319089192bcf1443517dbc3eac821f045c02b3c4f3e9519",
+        ],
+    },
+];
+
+#[test]
+fn test_pallas_xmd_blake2b_sswu_ro() {
+    let dst = PALLAS_XMD_BLAKE2B_SSWU_RO_DST;
+    let hasher = Hasher::<
+        SWProjective<PallasConfig>,
+        OptimizedSWUHashToCurve<PallasOSWUMap>,
+        XmdFieldHasher<Blake2b512, 256>,
+    >::new(dst);
+
+    for v in PALLAS_XMD_BLAKE2B_SSWU_RO.iter() {
+        let msg = v.msg.as_bytes();
+        let u = OptimizedSWUHashToCurve::<PallasOSWUMap>::hash_to_field::<
+            XmdFieldHasher<Blake2b512, 256>,
+        >(msg, dst, 2);
+        assert_eq!(u, vec![field_from_hex(v.u[0]), field_from_hex(v.u[1])]);
+
+        let p: SWAffine<PallasConfig> = affine_from_hex(&v.p);
+        assert_eq!(
+            SWAffine::from(OptimizedSWUHashToCurve::<PallasOSWUMap>::hash_to_curve(
+                msg, dst
+            )),
+            p
+        );
+        assert_eq!(SWAffine::from(hasher.hash(msg)), p);
+    }
+}
+
+#[test]
+fn test_vesta_xmd_blake2b_sswu_ro() {
+    let dst = VESTA_XMD_BLAKE2B_SSWU_RO_DST;
+    let hasher = Hasher::<
+        SWProjective<VestaConfig>,
+        OptimizedSWUHashToCurve<VestaOSWUMap>,
+        XmdFieldHasher<Blake2b512, 256>,
+    >::new(dst);
+
+    for v in VESTA_XMD_BLAKE2B_SSWU_RO.iter() {
+        let msg = v.msg.as_bytes();
+        let u = OptimizedSWUHashToCurve::<VestaOSWUMap>::hash_to_field::<
+            XmdFieldHasher<Blake2b512, 256>,
+        >(msg, dst, 2);
+        assert_eq!(u, vec![field_from_hex(v.u[0]), field_from_hex(v.u[1])]);
+
+        let p: SWAffine<VestaConfig> = affine_from_hex(&v.p);
+        assert_eq!(
+            SWAffine::from(OptimizedSWUHashToCurve::<VestaOSWUMap>::hash_to_curve(
+                msg, dst
+            )),
+            p
+        );
+        assert_eq!(SWAffine::from(hasher.hash(msg)), p);
+    }
+}
+
+#[test]
+fn test_pasta_exceptional_inputs() {
+    // map_to_curve_simple_swu(0) onto iso-Pallas and iso-Vesta in Jacobian coordinates of the `pasta_curves` crate
+    let (x, y, z) = PallasOSWUMap::isogeny_map(
+        &field_from_hex("28c1a6a534f56c52e25295b339129a8af5f42525dea727f485ca3433519b096e"),
+        &field_from_hex("3bfc658bee6653c63c7d7f0927083fd315d29c270207b7c7084fa1ee6ac5ae8d"),
+        &field_from_hex("054b3ba10416dc104157b1318534a19d5d115472da7d746f8a5f250cd8cdef36"),
+    );
+    let q = PallasOSWUMap::map_to_curve(&Zero::zero());
+    assert_eq!(q, SWProjective::<PallasConfig>::new_unchecked(x, y, z));
+    assert_eq!(
+        q,
+        ConstantTime::<PallasOSWUMap>::map_to_curve(&Zero::zero())
+    );
+
+    let (x, y, z) = VestaOSWUMap::isogeny_map(
+        &field_from_hex("2ccc4c6ec2660e5644305bc52527d904d408f92407f599df8f158d50646a2e78"),
+        &field_from_hex("29a34381321d13d72d50b6b462bb4ea6a9e47393fa28a47227bf35bc0ee7aa59"),
+        &field_from_hex("0b851e9e579403a76df1100f556e1f226e5656bdf38f3bf8601d8a3a9a15890b"),
+    );
+    let q = VestaOSWUMap::map_to_curve(&Zero::zero());
+    assert_eq!(q, SWProjective::<VestaConfig>::new_unchecked(x, y, z));
+    assert!(SWAffine::from(q).is_on_curve());
+}
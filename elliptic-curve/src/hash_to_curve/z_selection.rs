@@ -0,0 +1,264 @@
+use ark_ec::short_weierstrass::SWCurveConfig;
+use ark_ff::{Field, One, Zero};
+use ark_std::ops::*;
+use ark_std::{vec, vec::Vec};
+
+/// Find the constant Z of the Shallue-van de Woestijne map for the curve `P`, which is the first one
+/// of 1, -1, 2, -2, ... meeting the criteria of `SWMap::Z`,
+/// see <https://www.rfc-editor.org/rfc/rfc9380.html#appendix-H.1>
+pub fn find_z_svdw<P: SWCurveConfig>() -> P::BaseField {
+    let a = P::COEFF_A;
+    let b = P::COEFF_B;
+    let g = |x: &P::BaseField| x.square().add(&a).mul(x).add(&b);
+    let h = |z: &P::BaseField| {
+        let num = z
+            .square()
+            .mul(&P::BaseField::from(3u64))
+            .add(&a.double().double());
+        num.neg().div(&g(z).double().double())
+    };
+
+    let mut ctr = P::BaseField::one();
+    loop {
+        for z in [ctr, ctr.neg()] {
+            // criterion 1: g(Z) != 0
+            if g(&z).is_zero() {
+                continue;
+            }
+
+            // criteria 2 and 3: -(3 * Z^2 + 4 * A) / (4 * g(Z)) is a nonzero square
+            let h_z = h(&z);
+            if h_z.is_zero() || !is_square(&h_z) {
+                continue;
+            }
+
+            // criterion 4: at least one of g(Z) and g(-Z / 2) is square
+            if is_square(&g(&z)) || is_square(&g(&z.neg().div(&P::BaseField::from(2u64)))) {
+                return z;
+            }
+        }
+        ctr.add_assign(&P::BaseField::one());
+    }
+}
+
+/// Find the constant Z of the Simplified SWU map for the curve `P` with A != 0 and B != 0,
+/// see `find_z_sswu_with_coeffs` for the isogenous curves
+pub fn find_z_sswu<P: SWCurveConfig>() -> P::BaseField {
+    find_z_sswu_with_coeffs(&P::COEFF_A, &P::COEFF_B)
+}
+
+/// Find the constant Z of the Simplified SWU map for the curve y^2 = g(x) = x^3 + a * x + b
+/// with a != 0 and b != 0, e.g. the isogenous curve of a map whose A and B are not the curve's own.
+/// The candidates are ctr, -ctr for ctr = 1, 2, ... in the prime field, and for ctr = I, I + 1, ...
+/// in the extension field whose generator is I, e.g. Z = -(2 + I) of BLS12-381 G2.
+///
+/// Z meets the criteria:
+/// 1. Z is non-square
+/// 2. Z != -1
+/// 3. g(x) - Z is irreducible, i.e. the cubic has no root
+/// 4. g(b / (Z * a)) is square
+///
+/// see <https://www.rfc-editor.org/rfc/rfc9380.html#appendix-H.2>
+pub fn find_z_sswu_with_coeffs<F: Field>(a: &F, b: &F) -> F {
+    let g = |x: &F| x.square().add(a).mul(x).add(b);
+
+    let mut ctr = generator::<F>();
+    loop {
+        for z in [ctr, ctr.neg()] {
+            if is_square(&z) || z == F::one().neg() {
+                continue;
+            }
+            if has_root(&[b.sub(&z), *a, F::zero(), F::one()]) {
+                continue;
+            }
+            if is_square(&g(&b.div(&z.mul(a)))) {
+                return z;
+            }
+        }
+        ctr.add_assign(&F::one());
+    }
+}
+
+/// Find the constant Z of the Elligator 2 map, which is the first non-square of ctr, -ctr
+/// in the same order as `find_z_sswu_with_coeffs`,
+/// see <https://www.rfc-editor.org/rfc/rfc9380.html#appendix-H.3>
+pub fn find_z_ell2<F: Field>() -> F {
+    let mut ctr = generator::<F>();
+    loop {
+        for z in [ctr, ctr.neg()] {
+            if !is_square(&z) {
+                return z;
+            }
+        }
+        ctr.add_assign(&F::one());
+    }
+}
+
+/// The generator of the field over its prime field, i.e. 1 for the prime field and I for Fp2 = Fp[I]
+fn generator<F: Field>() -> F {
+    if F::extension_degree() == 1 {
+        return F::one();
+    }
+    let mut elems = vec![F::BasePrimeField::from(0u64); F::extension_degree() as usize];
+    elems[1] = F::BasePrimeField::from(1u64);
+    F::from_base_prime_field_elems(&elems).unwrap()
+}
+
+/// is_square(x) in any field, including 0
+fn is_square<F: Field>(x: &F) -> bool {
+    !x.legendre().is_qnr()
+}
+
+/// Whether the monic cubic f = f_0 + f_1 * x + f_2 * x^2 + x^3 has a root in the field of size q,
+/// i.e. gcd(f, x^q - x) != 1
+fn has_root<F: Field>(f: &[F; 4]) -> bool {
+    // x^q mod f by raising to the characteristic p once per extension degree
+    let mut x_q = vec![F::zero(), F::one()];
+    for _ in 0..F::extension_degree() {
+        let mut acc = vec![F::one()];
+        for limb in F::characteristic().iter().rev() {
+            for i in (0..64).rev() {
+                acc = rem(&mul(&acc, &acc), f);
+                if (limb >> i) & 1 == 1 {
+                    acc = rem(&mul(&acc, &x_q), f);
+                }
+            }
+        }
+        x_q = acc;
+    }
+
+    // x^q - x
+    x_q.resize(x_q.len().max(2), F::zero());
+    x_q[1].sub_assign(&F::one());
+
+    let mut u = f.to_vec();
+    let mut v = trim(x_q);
+    while !v.is_empty() {
+        let r = rem(&u, &v);
+        u = v;
+        v = r;
+    }
+    u.len() > 1
+}
+
+/// Drop the leading zero coefficients, the coefficients are in ascending order of the degree
+fn trim<F: Field>(mut f: Vec<F>) -> Vec<F> {
+    while f.last().is_some_and(|c| c.is_zero()) {
+        f.pop();
+    }
+    f
+}
+
+fn mul<F: Field>(f: &[F], g: &[F]) -> Vec<F> {
+    let mut h = vec![F::zero(); f.len() + g.len()];
+    for (i, c) in f.iter().enumerate() {
+        for (j, d) in g.iter().enumerate() {
+            h[i + j].add_assign(c.mul(d));
+        }
+    }
+    trim(h)
+}
+
+/// f mod g for the nonzero g
+fn rem<F: Field>(f: &[F], g: &[F]) -> Vec<F> {
+    let g = trim(g.to_vec());
+    let lead_inv = g[g.len() - 1].inverse().unwrap();
+    let mut r = trim(f.to_vec());
+    while r.len() >= g.len() {
+        let shift = r.len() - g.len();
+        let c = r[r.len() - 1].mul(&lead_inv);
+        for (i, e) in g.iter().enumerate() {
+            r[shift + i].sub_assign(c.mul(e));
+        }
+        r = trim(r);
+    }
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ec::short_weierstrass::SWCurveConfig;
+    use ark_ec::twisted_edwards::TECurveConfig;
+    use ark_ff::PrimeField;
+
+    use super::{find_z_ell2, find_z_sswu_with_coeffs, find_z_svdw};
+    use crate::hash_to_curve::elligator2::bandersnatch_ell2::BandersnatchEll2Map;
+    use crate::hash_to_curve::elligator2::curve25519_ell2::Curve25519Ell2Map;
+    use crate::hash_to_curve::elligator2::edwards25519_ell2::Edwards25519Ell2Map;
+    use crate::hash_to_curve::elligator2::jubjub_ell2::JubjubEll2Map;
+    use crate::hash_to_curve::elligator2::Elligator2Map;
+    use crate::hash_to_curve::optimized_swu::bls12381G1_oswu::Bls12381G1OSWUMap;
+    use crate::hash_to_curve::optimized_swu::pallas_oswu::PallasOSWUMap;
+    use crate::hash_to_curve::optimized_swu::secp256K1_oswu::Secp256K1OSWUMap;
+    use crate::hash_to_curve::optimized_swu::secp256R1_oswu::Secp256R1OSWUMap;
+    use crate::hash_to_curve::optimized_swu::secp384R1_oswu::Secp384R1OSWUMap;
+    use crate::hash_to_curve::optimized_swu::secp521R1_oswu::Secp521R1OSWUMap;
+    use crate::hash_to_curve::optimized_swu::vesta_oswu::VestaOSWUMap;
+    use crate::hash_to_curve::optimized_swu::OptimizedSWUMap;
+    use crate::hash_to_curve::simplified_swu::bls12381G1_sswu::Bls12381G1SSWUMap;
+    use crate::hash_to_curve::simplified_swu::bls12381G2_sswu::Bls12381G2SSWUMap;
+    use crate::hash_to_curve::simplified_swu::secp256K1_sswu::Secp256K1SSWUMap;
+    use crate::hash_to_curve::simplified_swu::secp256R1_sswu::Secp256R1SSWUMap;
+    use crate::hash_to_curve::simplified_swu::secp384R1_sswu::Secp384R1SSWUMap;
+    use crate::hash_to_curve::simplified_swu::secp521R1_sswu::Secp521R1SSWUMap;
+    use crate::hash_to_curve::simplified_swu::SimplifiedSWUMap;
+    use crate::hash_to_curve::sw_map::bn254G1_sw::Bn254G1SWMap;
+    use crate::hash_to_curve::sw_map::bn254G2_sw::Bn254G2SWMap;
+    use crate::hash_to_curve::sw_map::secp256K1_sw::Secp256K1SWMap;
+    use crate::hash_to_curve::sw_map::SWMap;
+
+    fn check_svdw<P: SWCurveConfig, M: SWMap<P>>() {
+        assert_eq!(M::Z, find_z_svdw::<P>());
+    }
+
+    fn check_sswu<P: SWCurveConfig, M: SimplifiedSWUMap<P>>() {
+        assert_eq!(M::Z, find_z_sswu_with_coeffs(&M::A, &M::B));
+    }
+
+    fn check_oswu<P: SWCurveConfig, M: OptimizedSWUMap<P>>()
+    where
+        P::BaseField: PrimeField,
+    {
+        assert_eq!(M::Z, find_z_sswu_with_coeffs(&M::A, &M::B));
+    }
+
+    fn check_ell2<P: TECurveConfig, M: Elligator2Map<P>>() {
+        assert_eq!(M::Z, find_z_ell2());
+    }
+
+    #[test]
+    fn test_z_of_sw_maps() {
+        check_svdw::<ark_secp256k1::Config, Secp256K1SWMap>();
+        check_svdw::<ark_bn254::g1::Config, Bn254G1SWMap>();
+        check_svdw::<ark_bn254::g2::Config, Bn254G2SWMap>();
+    }
+
+    #[test]
+    fn test_z_of_simplified_swu_maps() {
+        check_sswu::<ark_secp256k1::Config, Secp256K1SSWUMap>();
+        check_sswu::<ark_secp256r1::Config, Secp256R1SSWUMap>();
+        check_sswu::<ark_secp384r1::Config, Secp384R1SSWUMap>();
+        check_sswu::<crate::curves::secp521r1::Config, Secp521R1SSWUMap>();
+        check_sswu::<ark_bls12_381::g1::Config, Bls12381G1SSWUMap>();
+        check_sswu::<ark_bls12_381::g2::Config, Bls12381G2SSWUMap>();
+    }
+
+    #[test]
+    fn test_z_of_optimized_swu_maps() {
+        check_oswu::<ark_secp256k1::Config, Secp256K1OSWUMap>();
+        check_oswu::<ark_secp256r1::Config, Secp256R1OSWUMap>();
+        check_oswu::<ark_secp384r1::Config, Secp384R1OSWUMap>();
+        check_oswu::<crate::curves::secp521r1::Config, Secp521R1OSWUMap>();
+        check_oswu::<ark_bls12_381::g1::Config, Bls12381G1OSWUMap>();
+        check_oswu::<ark_pallas::PallasConfig, PallasOSWUMap>();
+        check_oswu::<ark_vesta::VestaConfig, VestaOSWUMap>();
+    }
+
+    #[test]
+    fn test_z_of_elligator2_maps() {
+        check_ell2::<ark_curve25519::Curve25519Config, Curve25519Ell2Map>();
+        check_ell2::<ark_ed25519::EdwardsConfig, Edwards25519Ell2Map>();
+        check_ell2::<ark_ed_on_bls12_381::JubjubConfig, JubjubEll2Map>();
+        check_ell2::<ark_ed_on_bls12_381_bandersnatch::BandersnatchConfig, BandersnatchEll2Map>();
+    }
+}
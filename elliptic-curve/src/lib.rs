@@ -1,2 +1,10 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "alloc"))]
+compile_error!("the elliptic-curve crate needs an allocator, enable the `std` or `alloc` feature");
+
+/// Module for the curves that are not provided by arkworks
+pub mod curves;
+
 /// Module for hasing to the curve
 pub mod hash_to_curve;
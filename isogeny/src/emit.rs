@@ -0,0 +1,325 @@
+use ark_ec::short_weierstrass::SWCurveConfig;
+use ark_ff::{FftField, Field, PrimeField};
+use std::fmt::Write;
+
+use crate::velu::Velu;
+
+/// The maximum width of the emitted lines, which is the default of rustfmt
+const MAX_WIDTH: usize = 100;
+
+/// The hash function of expand_message_xmd in the emitted `XmdFieldHasher`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum XmdHash {
+    Sha256,
+    Sha384,
+    Sha512,
+    Blake2b512,
+}
+
+impl XmdHash {
+    /// Parse the hash function from its name on the command line, e.g. `sha256`
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "sha256" => Some(XmdHash::Sha256),
+            "sha384" => Some(XmdHash::Sha384),
+            "sha512" => Some(XmdHash::Sha512),
+            "blake2b512" => Some(XmdHash::Blake2b512),
+            _ => None,
+        }
+    }
+
+    /// The path of the hash function type, e.g. `sha2::Sha256`
+    pub fn path(self) -> &'static str {
+        match self {
+            XmdHash::Sha256 => "sha2::Sha256",
+            XmdHash::Sha384 => "sha2::Sha384",
+            XmdHash::Sha512 => "sha2::Sha512",
+            XmdHash::Blake2b512 => "blake2::Blake2b512",
+        }
+    }
+
+    /// The name of the hash function type, e.g. `Sha256`
+    pub fn type_name(self) -> &'static str {
+        let path = self.path();
+        &path[path.rfind(':').unwrap() + 1..]
+    }
+
+    /// The name of the hash function in the documents, e.g. `SHA-256`
+    pub fn doc_name(self) -> &'static str {
+        match self {
+            XmdHash::Sha256 => "SHA-256",
+            XmdHash::Sha384 => "SHA-384",
+            XmdHash::Sha512 => "SHA-512",
+            XmdHash::Blake2b512 => "BLAKE2b-512",
+        }
+    }
+}
+
+/// The standard hash-to-curve suites which the instance implements
+pub struct Suite<'a> {
+    /// The prefix of the suite IDs before `_RO_` and `_NU_`, e.g. `secp256k1_XMD:SHA-256_SSWU`
+    pub id: &'a str,
+
+    /// The specification of the suites, e.g. `RFC 9380`
+    pub spec: &'a str,
+}
+
+/// The instance of the Optimized SWU map for the curve `P` to emit
+pub struct Instance<'a, P: SWCurveConfig> {
+    /// The name of the struct, e.g. `Secp256K1OSWUMap`
+    pub name: &'a str,
+
+    /// The name of the curve in the documents, e.g. `secp256k1`
+    pub curve: &'a str,
+
+    /// The crate of the curve, e.g. `ark_secp256k1`
+    pub krate: &'a str,
+
+    /// The curve config in the crate, e.g. `Config`
+    pub config: &'a str,
+
+    /// The default domain separation tag
+    pub dst: &'a str,
+
+    /// The parameters A' and B' of the isogenous curve
+    pub a: P::BaseField,
+    pub b: P::BaseField,
+
+    /// The constant Z of the isogenous curve
+    pub z: P::BaseField,
+
+    /// The isogeny from the isogenous curve to the curve
+    pub isogeny: &'a Velu<P::BaseField>,
+
+    /// The hash function and the security level k in bits of hash_to_field
+    pub hash: XmdHash,
+    pub sec_param: usize,
+
+    /// The standard suites, which are only named if the instance implements them
+    pub suite: Option<Suite<'a>>,
+}
+
+impl<'a, P: SWCurveConfig> Instance<'a, P>
+where
+    P::BaseField: PrimeField,
+{
+    /// The source of the instance module, which compiles in `hash_to_curve::optimized_swu`
+    /// in the layout of `secp256K1_oswu.rs`
+    pub fn module(&self) -> String {
+        let degree = self.isogeny.x_num.len() - 1;
+        let mut out = String::new();
+
+        writeln!(out, "use ark_ff::MontFp;").unwrap();
+        let mut imports = [self.config, "Fq"];
+        imports.sort();
+        writeln!(out, "use {}::{{{}}};", self.krate, imports.join(", ")).unwrap();
+        writeln!(out, "use {};", self.hash.path()).unwrap();
+        writeln!(out).unwrap();
+        writeln!(
+            out,
+            "use crate::hash_to_curve::field_hasher::XmdFieldHasher;"
+        )
+        .unwrap();
+        writeln!(out, "use crate::hash_to_curve::isogeny::Isogeny;").unwrap();
+        writeln!(out).unwrap();
+        writeln!(out, "use super::OptimizedSWUMap;").unwrap();
+        writeln!(out).unwrap();
+
+        writeln!(
+            out,
+            "/// The Optimized SWU map for {}, which maps to the {}-isogenous curve",
+            self.curve, degree
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "/// iso-{}: y^2 = x^3 + A' * x + B' and then to {} by the {}-isogeny.",
+            self.curve, self.curve, degree
+        )
+        .unwrap();
+        writeln!(out, "pub struct {};", self.name).unwrap();
+        writeln!(out).unwrap();
+
+        writeln!(
+            out,
+            "/// The {}-isogeny map from iso-{} to {}, the coefficients are in ascending order of the degree",
+            degree, self.curve, self.curve
+        )
+        .unwrap();
+        for (i, (table, coeffs)) in [
+            ("X_MAP_NUMERATOR", &self.isogeny.x_num),
+            ("X_MAP_DENOMINATOR", &self.isogeny.x_den),
+            ("Y_MAP_NUMERATOR", &self.isogeny.y_num),
+            ("Y_MAP_DENOMINATOR", &self.isogeny.y_den),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            if i > 0 {
+                writeln!(out).unwrap();
+            }
+            writeln!(out, "const {}: [Fq; {}] = [", table, coeffs.len()).unwrap();
+            for coeff in coeffs.iter() {
+                writeln!(out, "    MontFp!(\"{}\"),", decimal(coeff)).unwrap();
+            }
+            writeln!(out, "];").unwrap();
+        }
+        writeln!(out).unwrap();
+
+        writeln!(
+            out,
+            "/// The {}-isogeny from iso-{} to {} evaluated from the tables above",
+            degree, self.curve, self.curve
+        )
+        .unwrap();
+        writeln!(out, "const ISOGENY: Isogeny<'static, Fq> = Isogeny::new(").unwrap();
+        for table in [
+            "X_MAP_NUMERATOR",
+            "X_MAP_DENOMINATOR",
+            "Y_MAP_NUMERATOR",
+            "Y_MAP_DENOMINATOR",
+        ] {
+            writeln!(out, "    &{},", table).unwrap();
+        }
+        writeln!(out, ");").unwrap();
+        writeln!(out).unwrap();
+
+        writeln!(
+            out,
+            "impl OptimizedSWUMap<{}> for {} {{",
+            self.config, self.name
+        )
+        .unwrap();
+        constant(
+            &mut out,
+            "Z",
+            "Fq",
+            &format!("MontFp!(\"{}\")", decimal(&self.z)),
+        );
+        writeln!(out).unwrap();
+        match self.c2() {
+            Some(c2) => {
+                let value = format!("Some(MontFp!(\"{}\"))", decimal(&c2));
+                if "    const C2: Option<Fq> = ;".len() + value.len() <= MAX_WIDTH {
+                    writeln!(out, "    const C2: Option<Fq> = {};", value).unwrap();
+                } else {
+                    writeln!(out, "    const C2: Option<Fq> = Some(MontFp!(").unwrap();
+                    writeln!(out, "        \"{}\"", decimal(&c2)).unwrap();
+                    writeln!(out, "    ));").unwrap();
+                }
+            }
+            None => {
+                writeln!(out, "    /// c2 is only used for p = 3 mod 4").unwrap();
+                writeln!(out, "    const C2: Option<Fq> = None;").unwrap();
+            }
+        }
+        writeln!(out).unwrap();
+        constant(
+            &mut out,
+            "A",
+            "Fq",
+            &format!("MontFp!(\"{}\")", decimal(&self.a)),
+        );
+        writeln!(out).unwrap();
+        constant(
+            &mut out,
+            "B",
+            "Fq",
+            &format!("MontFp!(\"{}\")", decimal(&self.b)),
+        );
+        writeln!(out).unwrap();
+        writeln!(out, "    const DST: &'static [u8] = b\"{}\";", self.dst).unwrap();
+        writeln!(out).unwrap();
+        self.field_hasher(&mut out);
+        writeln!(out).unwrap();
+        writeln!(
+            out,
+            "    fn isogeny_map(isogeny_x: &Fq, isogeny_y: &Fq, isogeny_z: &Fq) -> (Fq, Fq, Fq) {{"
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "        ISOGENY.map_jacobian(isogeny_x, isogeny_y, isogeny_z)"
+        )
+        .unwrap();
+        writeln!(out, "    }}").unwrap();
+        writeln!(out, "}}").unwrap();
+        out
+    }
+
+    /// The associated type `FieldHasher`, whose document names the suites only if given
+    fn field_hasher(&self, out: &mut String) {
+        match &self.suite {
+            Some(suite) => {
+                writeln!(
+                    out,
+                    "    /// The hash_to_field function of the suites `{}_RO_`",
+                    suite.id
+                )
+                .unwrap();
+                writeln!(out, "    /// and `{}_NU_` of {}", suite.id, suite.spec).unwrap();
+            }
+            None => {
+                writeln!(
+                    out,
+                    "    /// The hash_to_field function by expand_message_xmd with {}",
+                    self.hash.doc_name()
+                )
+                .unwrap();
+                writeln!(
+                    out,
+                    "    /// for the security level of {} bits",
+                    self.sec_param
+                )
+                .unwrap();
+            }
+        }
+        if self.sec_param == 128 {
+            writeln!(
+                out,
+                "    type FieldHasher = XmdFieldHasher<{}>;",
+                self.hash.type_name()
+            )
+            .unwrap();
+        } else {
+            writeln!(
+                out,
+                "    type FieldHasher = XmdFieldHasher<{}, {}>;",
+                self.hash.type_name(),
+                self.sec_param
+            )
+            .unwrap();
+        }
+    }
+
+    /// The constant c2 = sqrt(-Z^3) of `sqrt_ratio_3mod4` for p = 3 mod 4
+    pub fn c2(&self) -> Option<P::BaseField> {
+        if P::BaseField::TWO_ADICITY == 1 {
+            (-(self.z.square() * self.z)).sqrt()
+        } else {
+            None
+        }
+    }
+}
+
+/// The decimal of the field element, which is negative for the small ones like Z = -11
+fn decimal<F: PrimeField>(value: &F) -> String {
+    let positive = value.into_bigint().to_string();
+    let negative = format!("-{}", (-*value).into_bigint());
+    if 2 * negative.len() < positive.len() {
+        negative
+    } else {
+        positive
+    }
+}
+
+/// The associated constant in an impl block, broken after `=` if the line is too long
+fn constant(out: &mut String, name: &str, ty: &str, value: &str) {
+    let line = format!("    const {}: {} = {};", name, ty, value);
+    if line.len() <= MAX_WIDTH {
+        writeln!(out, "{}", line).unwrap();
+    } else {
+        writeln!(out, "    const {}: {} =", name, ty).unwrap();
+        writeln!(out, "        {};", value).unwrap();
+    }
+}
@@ -0,0 +1,14 @@
+/// Module for the polynomials over a field and their roots in the prime field
+pub mod poly;
+
+/// Module for the division polynomials, the kernels and Vélu's formulas
+pub mod velu;
+
+/// Module for searching the isogenous curves and the isogeny maps back to the origin curve
+pub mod search;
+
+/// Module for emitting the instance modules of the Optimized SWU map
+pub mod emit;
+
+#[cfg(test)]
+mod tests;
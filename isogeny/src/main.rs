@@ -0,0 +1,241 @@
+use ark_ec::short_weierstrass::SWCurveConfig;
+use ark_ff::PrimeField;
+use std::env;
+use std::process;
+
+use elliptic_curve::hash_to_curve::z_selection::find_z_sswu_with_coeffs;
+use isogeny::emit::{Instance, Suite, XmdHash};
+use isogeny::search::{find_isogenous_curves, isogeny_map};
+
+const USAGE: &str = "usage: isogeny <secp256k1|pallas|vesta> [--a A' --b B'] [--degree D] [--max-degree N]
+    [--hash <sha256|sha384|sha512|blake2b512>] [--sec-param K] [--name NAME] [--dst DST]
+
+Search the isogenies of the odd prime degrees up to N (29 by default) from the curve to the curves with
+A' * B' != 0 by Vélu's formulas, and print the instance module of the Optimized SWU map to stdout.
+The isogenous curve is the standard one of the curve unless A' and B' are given in decimal,
+and the degree is the one of the matching candidate unless D is given.
+The hash function and the security level K in bits of hash_to_field default to the standard suites.";
+
+/// The standard isogenous curve and hash_to_field function of a curve
+struct Standard {
+    a: &'static str,
+    b: &'static str,
+    hash: XmdHash,
+    sec_param: usize,
+    suite: Suite<'static>,
+}
+
+/// The suites `secp256k1_XMD:SHA-256_SSWU_RO_` and `secp256k1_XMD:SHA-256_SSWU_NU_`,
+/// see <https://www.rfc-editor.org/rfc/rfc9380.html#section-8.7>
+const SECP256K1: Standard = Standard {
+    a: "28734576633528757162648956269730739219262246272443394170905244663053633733939",
+    b: "1771",
+    hash: XmdHash::Sha256,
+    sec_param: 128,
+    suite: Suite {
+        id: "secp256k1_XMD:SHA-256_SSWU",
+        spec: "RFC 9380",
+    },
+};
+
+/// The suites `pallas_XMD:BLAKE2b_SSWU_RO_` and `pallas_XMD:BLAKE2b_SSWU_NU_` of `pasta_curves`
+const PALLAS: Standard = Standard {
+    a: "10949663248450308183708987909873589833737836120165333298109615750520499732811",
+    b: "1265",
+    hash: XmdHash::Blake2b512,
+    sec_param: 256,
+    suite: Suite {
+        id: "pallas_XMD:BLAKE2b_SSWU",
+        spec: "the `pasta_curves` crate",
+    },
+};
+
+/// The suites `vesta_XMD:BLAKE2b_SSWU_RO_` and `vesta_XMD:BLAKE2b_SSWU_NU_` of `pasta_curves`
+const VESTA: Standard = Standard {
+    a: "17413348858408915339762682399132325137863850198379221683097628341577494210225",
+    b: "1265",
+    hash: XmdHash::Blake2b512,
+    sec_param: 256,
+    suite: Suite {
+        id: "vesta_XMD:BLAKE2b_SSWU",
+        spec: "the `pasta_curves` crate",
+    },
+};
+
+/// The options of the command line
+struct Options {
+    a: Option<String>,
+    b: Option<String>,
+    degree: Option<usize>,
+    max_degree: usize,
+    hash: Option<XmdHash>,
+    sec_param: Option<usize>,
+    name: Option<String>,
+    dst: Option<String>,
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let Some(curve) = args.first() else {
+        exit(USAGE);
+    };
+    let options = parse_options(&args[1..]);
+
+    match curve.as_str() {
+        "secp256k1" => run::<ark_secp256k1::Config>(
+            &options,
+            "secp256k1",
+            "ark_secp256k1",
+            "Config",
+            "Secp256K1OSWUMap",
+            Some(SECP256K1),
+        ),
+        "pallas" => run::<ark_pallas::PallasConfig>(
+            &options,
+            "pallas",
+            "ark_pallas",
+            "PallasConfig",
+            "PallasOSWUMap",
+            Some(PALLAS),
+        ),
+        "vesta" => run::<ark_vesta::VestaConfig>(
+            &options,
+            "vesta",
+            "ark_vesta",
+            "VestaConfig",
+            "VestaOSWUMap",
+            Some(VESTA),
+        ),
+        _ => exit(USAGE),
+    }
+}
+
+fn run<P: SWCurveConfig>(
+    options: &Options,
+    curve: &str,
+    krate: &str,
+    config: &str,
+    name: &str,
+    standard: Option<Standard>,
+) where
+    P::BaseField: PrimeField,
+{
+    let curves = find_isogenous_curves::<P>(options.max_degree);
+    for iso in curves.iter() {
+        eprintln!(
+            "{}-isogenous curve: A' = {}, B' = {}",
+            iso.degree,
+            iso.a.into_bigint(),
+            iso.b.into_bigint()
+        );
+    }
+
+    let (a, b) = match (&options.a, &options.b, &standard) {
+        (Some(a), Some(b), _) => (parse_field(a), parse_field(b)),
+        (None, None, Some(standard)) => (parse_field(standard.a), parse_field(standard.b)),
+        (None, None, None) => {
+            exit("the curve has no standard isogenous curve, A' and B' are required")
+        }
+        _ => exit("both A' and B' are required"),
+    };
+    let degree = match options.degree {
+        Some(degree) => degree,
+        None => match curves.iter().find(|iso| iso.a == a && iso.b == b) {
+            Some(iso) => iso.degree,
+            None => {
+                exit("A' and B' are not one of the isogenous curves found, the degree is required")
+            }
+        },
+    };
+    let Some(isogeny) = isogeny_map::<P>(&a, &b, degree) else {
+        exit("the curve is not isogenous to the given A' and B' by the degree");
+    };
+
+    let (hash, sec_param) = match &standard {
+        Some(standard) => (
+            options.hash.unwrap_or(standard.hash),
+            options.sec_param.unwrap_or(standard.sec_param),
+        ),
+        None => (
+            options.hash.unwrap_or(XmdHash::Sha256),
+            options.sec_param.unwrap_or(128),
+        ),
+    };
+    // the suites are only named if the instance implements them
+    let suite = standard.and_then(|standard| {
+        let implements = (a, b) == (parse_field(standard.a), parse_field(standard.b))
+            && (hash, sec_param) == (standard.hash, standard.sec_param);
+        implements.then_some(standard.suite)
+    });
+
+    let dst = format!("{}_oswu", curve);
+    let instance = Instance::<P> {
+        name: options.name.as_deref().unwrap_or(name),
+        curve,
+        krate,
+        config,
+        dst: options.dst.as_deref().unwrap_or(&dst),
+        a,
+        b,
+        z: find_z_sswu_with_coeffs(&a, &b),
+        isogeny: &isogeny,
+        hash,
+        sec_param,
+        suite,
+    };
+    print!("{}", instance.module());
+}
+
+fn parse_options(args: &[String]) -> Options {
+    let mut options = Options {
+        a: None,
+        b: None,
+        degree: None,
+        max_degree: 29,
+        hash: None,
+        sec_param: None,
+        name: None,
+        dst: None,
+    };
+
+    let mut iter = args.iter();
+    while let Some(flag) = iter.next() {
+        let Some(value) = iter.next().cloned() else {
+            exit(USAGE);
+        };
+        match flag.as_str() {
+            "--a" => options.a = Some(value),
+            "--b" => options.b = Some(value),
+            "--degree" => {
+                options.degree = Some(value.parse().unwrap_or_else(|_| exit(USAGE)));
+            }
+            "--max-degree" => {
+                options.max_degree = value.parse().unwrap_or_else(|_| exit(USAGE));
+            }
+            "--hash" => {
+                options.hash = Some(XmdHash::parse(&value).unwrap_or_else(|| exit(USAGE)));
+            }
+            "--sec-param" => {
+                options.sec_param = Some(value.parse().unwrap_or_else(|_| exit(USAGE)));
+            }
+            "--name" => options.name = Some(value),
+            "--dst" => options.dst = Some(value),
+            _ => exit(USAGE),
+        }
+    }
+    options
+}
+
+/// Parse the field element in decimal, which may be negative
+fn parse_field<F: PrimeField>(s: &str) -> F {
+    let parse = |s: &str| F::from_str(s).unwrap_or_else(|_| exit("invalid field element"));
+    match s.strip_prefix('-') {
+        Some(s) => -parse(s),
+        None => parse(s),
+    }
+}
+
+fn exit(msg: &str) -> ! {
+    eprintln!("{}", msg);
+    process::exit(1);
+}
@@ -0,0 +1,168 @@
+use ark_ff::{BigInteger, Field, PrimeField};
+
+/// Drop the leading zero coefficients, the coefficients are in ascending order of the degree
+pub fn trim<F: Field>(mut f: Vec<F>) -> Vec<F> {
+    while f.last().is_some_and(|c| c.is_zero()) {
+        f.pop();
+    }
+    f
+}
+
+/// The degree of the polynomial, which is `None` for the zero polynomial
+pub fn degree<F: Field>(f: &[F]) -> Option<usize> {
+    f.iter().rposition(|c| !c.is_zero())
+}
+
+/// f + g
+pub fn add<F: Field>(f: &[F], g: &[F]) -> Vec<F> {
+    let mut h = vec![F::zero(); f.len().max(g.len())];
+    for (i, c) in f.iter().enumerate() {
+        h[i].add_assign(c);
+    }
+    for (i, c) in g.iter().enumerate() {
+        h[i].add_assign(c);
+    }
+    trim(h)
+}
+
+/// f - g
+pub fn sub<F: Field>(f: &[F], g: &[F]) -> Vec<F> {
+    add(f, &scale(g, &F::one().neg()))
+}
+
+/// c * f
+pub fn scale<F: Field>(f: &[F], c: &F) -> Vec<F> {
+    trim(f.iter().map(|e| e.mul(c)).collect())
+}
+
+/// f * g
+pub fn mul<F: Field>(f: &[F], g: &[F]) -> Vec<F> {
+    if f.is_empty() || g.is_empty() {
+        return vec![];
+    }
+    let mut h = vec![F::zero(); f.len() + g.len() - 1];
+    for (i, a) in f.iter().enumerate() {
+        for (j, b) in g.iter().enumerate() {
+            h[i + j].add_assign(a.mul(b));
+        }
+    }
+    trim(h)
+}
+
+/// The quotient and the remainder of f / g, where g is not zero
+pub fn div_rem<F: Field>(f: &[F], g: &[F]) -> (Vec<F>, Vec<F>) {
+    let g = trim(g.to_vec());
+    let g_deg = g.len() - 1;
+    let lead_inv = g[g_deg].inverse().unwrap();
+
+    let mut r = trim(f.to_vec());
+    if r.len() < g.len() {
+        return (vec![], r);
+    }
+    let mut q = vec![F::zero(); r.len() - g_deg];
+    while r.len() >= g.len() {
+        let shift = r.len() - g.len();
+        let c = r[r.len() - 1].mul(&lead_inv);
+        q[shift] = c;
+        for (i, e) in g.iter().enumerate() {
+            r[shift + i].sub_assign(c.mul(e));
+        }
+        r = trim(r);
+    }
+    (trim(q), r)
+}
+
+/// The monic greatest common divisor of f and g
+pub fn gcd<F: Field>(f: &[F], g: &[F]) -> Vec<F> {
+    let mut a = trim(f.to_vec());
+    let mut b = trim(g.to_vec());
+    while !b.is_empty() {
+        let (_, r) = div_rem(&a, &b);
+        a = b;
+        b = r;
+    }
+    monic(&a)
+}
+
+/// f divided by its leading coefficient
+pub fn monic<F: Field>(f: &[F]) -> Vec<F> {
+    match f.last() {
+        Some(lead) => scale(f, &lead.inverse().unwrap()),
+        None => vec![],
+    }
+}
+
+/// f^e mod m, the exponent is given by its big-endian bits
+pub fn pow_mod<F: Field>(f: &[F], e: &[bool], m: &[F]) -> Vec<F> {
+    let base = div_rem(f, m).1;
+    let mut acc = vec![F::one()];
+    for bit in e {
+        acc = div_rem(&mul(&acc, &acc), m).1;
+        if *bit {
+            acc = div_rem(&mul(&acc, &base), m).1;
+        }
+    }
+    acc
+}
+
+/// The formal derivative of f
+pub fn derivative<F: Field>(f: &[F]) -> Vec<F> {
+    trim(
+        f.iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, c)| c.mul(F::from(i as u64)))
+            .collect(),
+    )
+}
+
+/// Evaluate the polynomial at x by Horner's rule
+pub fn evaluate<F: Field>(f: &[F], x: &F) -> F {
+    f.iter().rev().fold(F::zero(), |acc, c| acc.mul(x).add(c))
+}
+
+/// The monic polynomial whose roots are `roots`
+pub fn from_roots<F: Field>(roots: &[F]) -> Vec<F> {
+    roots
+        .iter()
+        .fold(vec![F::one()], |acc, r| mul(&acc, &[r.neg(), F::one()]))
+}
+
+/// The distinct roots of f in the prime field, by gcd(f, x^p - x) and the Cantor-Zassenhaus splitting
+pub fn roots<F: PrimeField>(f: &[F]) -> Vec<F> {
+    let f = monic(f);
+    if degree(&f).unwrap_or(0) == 0 {
+        return vec![];
+    }
+
+    let x = [F::zero(), F::one()];
+    let x_p = pow_mod(&x, &F::MODULUS.to_bits_be(), &f);
+    let mut roots = split(&gcd(&f, &sub(&x_p, &x)));
+    roots.sort();
+    roots
+}
+
+/// Split the product of distinct linear factors h into its roots
+fn split<F: PrimeField>(h: &[F]) -> Vec<F> {
+    match degree(h) {
+        None | Some(0) => return vec![],
+        Some(1) => return vec![h[0].neg().mul(h[1].inverse().unwrap())],
+        _ => {}
+    }
+
+    // gcd(h, (x + delta)^((p - 1) / 2) - 1) is a proper factor of h for about half of the delta
+    let e = F::MODULUS_MINUS_ONE_DIV_TWO.to_bits_be();
+    let mut delta = F::zero();
+    loop {
+        let t = pow_mod(&[delta, F::one()], &e, h);
+        let g = gcd(h, &sub(&t, &[F::one()]));
+        let g_deg = degree(&g).unwrap_or(0);
+        if g_deg > 0 && g_deg < h.len() - 1 {
+            let (q, _) = div_rem(h, &g);
+            let mut roots = split(&g);
+            roots.extend(split(&q));
+            return roots;
+        }
+        delta.add_assign(F::one());
+    }
+}
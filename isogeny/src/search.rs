@@ -0,0 +1,143 @@
+use ark_ec::short_weierstrass::{Affine, SWCurveConfig};
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{Field, One, PrimeField, Zero};
+use std::ops::*;
+
+use crate::poly;
+use crate::velu::{kernels, Velu};
+
+/// The isogenous curve y^2 = x^3 + a * x + b reached by an isogeny of degree `degree`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IsogenousCurve<F: Field> {
+    pub degree: usize,
+    pub a: F,
+    pub b: F,
+}
+
+/// Look for the curves isogenous to `P` with A * B != 0, i.e. j-invariant not in {0, 1728},
+/// over the isogenies of the odd prime degrees up to `max_degree`.
+///
+/// It returns the codomains of Vélu's formulas for the smallest degree having any,
+/// as `isogenous-curve.sage` does with the primes below 30.
+pub fn find_isogenous_curves<P: SWCurveConfig>(
+    max_degree: usize,
+) -> Vec<IsogenousCurve<P::BaseField>>
+where
+    P::BaseField: PrimeField,
+{
+    for degree in (3..=max_degree).step_by(2).filter(|&n| is_prime(n)) {
+        let curves: Vec<_> = kernels(&P::COEFF_A, &P::COEFF_B, degree)
+            .iter()
+            .map(|kernel| Velu::new(&P::COEFF_A, &P::COEFF_B, kernel))
+            .filter(|velu| !velu.a.is_zero() && !velu.b.is_zero())
+            .map(|velu| IsogenousCurve {
+                degree,
+                a: velu.a,
+                b: velu.b,
+            })
+            .collect();
+        if !curves.is_empty() {
+            return curves;
+        }
+    }
+    vec![]
+}
+
+/// The isogeny from the isogenous curve y^2 = x^3 + a * x + b to `P`,
+/// which is the dual of an isogeny of degree `degree` from `P` to the isogenous curve.
+///
+/// The curve may be any model in the isomorphism class of a Vélu codomain, e.g. A' and B' of RFC 9380.
+/// Among the isogenies differing by the automorphisms of `P`, it takes the dual of the normalized isogeny,
+/// i.e. the Vélu isogeny whose codomain is (a, b) itself, if any, and fixes the sign such that
+/// y_num(1) / y_den(1) is the larger one of {v, p - v} as `isogeny-map.sage` does.
+/// It returns `None` if the curves are not `degree`-isogenous.
+pub fn isogeny_map<P: SWCurveConfig>(
+    a: &P::BaseField,
+    b: &P::BaseField,
+    degree: usize,
+) -> Option<Velu<P::BaseField>>
+where
+    P::BaseField: PrimeField,
+{
+    // the isogeny from P to the isogenous curve, scaled to the exact model (a, b),
+    // preferring the normalized one with the leading coefficient 1 of x_num as Sage finds it from the codomain
+    let forwards: Vec<_> = kernels(&P::COEFF_A, &P::COEFF_B, degree)
+        .iter()
+        .flat_map(|kernel| isomorphic(&Velu::new(&P::COEFF_A, &P::COEFF_B, kernel), a, b))
+        .collect();
+    let forward = forwards
+        .iter()
+        .find(|velu| velu.x_num.last().is_some_and(|c| c.is_one()))
+        .or(forwards.first())?;
+
+    // psi(phi(P)) = [degree] * P up to the sign
+    let points = sample_points::<P>(4);
+    let is_dual = |candidate: &Velu<P::BaseField>| {
+        points.iter().all(|point| {
+            let expected = point.mul_bigint([degree as u64]).into_affine();
+            let image = forward
+                .map(&point.x, &point.y)
+                .and_then(|(x, y)| candidate.map(&x, &y));
+            match (image, expected.xy()) {
+                (Some((x, y)), Some((ex, ey))) => x == *ex && (y == *ey || y == ey.neg()),
+                _ => false,
+            }
+        })
+    };
+
+    let mut dual = kernels(a, b, degree)
+        .iter()
+        .flat_map(|kernel| isomorphic(&Velu::new(a, b, kernel), &P::COEFF_A, &P::COEFF_B))
+        .find(is_dual)?;
+
+    let y = poly::evaluate(&dual.y_num, &P::BaseField::one())
+        .mul(poly::evaluate(&dual.y_den, &P::BaseField::one()).inverse()?);
+    if y.into_bigint() < y.neg().into_bigint() {
+        dual.y_num = poly::scale(&dual.y_num, &P::BaseField::one().neg());
+    }
+    Some(dual)
+}
+
+/// The isogeny composed with each isomorphism from its codomain to y^2 = x^3 + a * x + b,
+/// i.e. (x, y) -> (s * x, u * y) with s^2 * a' = a, s^3 * b' = b and u^2 = s^3
+fn isomorphic<F: PrimeField>(velu: &Velu<F>, a: &F, b: &F) -> Vec<Velu<F>> {
+    // the roots of a' * x^2 - a and b' * x^3 - b, either polynomial may vanish
+    let s = poly::gcd(
+        &poly::trim(vec![a.neg(), F::zero(), velu.a]),
+        &poly::trim(vec![b.neg(), F::zero(), F::zero(), velu.b]),
+    );
+    poly::roots(&s)
+        .into_iter()
+        .filter(|s| !s.is_zero())
+        .filter_map(|s| Some((s, s.square().mul(&s).sqrt()?)))
+        .flat_map(|(s, u)| {
+            [u, u.neg()].map(|u| {
+                let mut velu = velu.clone();
+                velu.scale(&s, &u);
+                velu
+            })
+        })
+        .collect()
+}
+
+/// The affine points of `P` from the smallest x-coordinates
+fn sample_points<P: SWCurveConfig>(n: usize) -> Vec<Affine<P>> {
+    let mut points = vec![];
+    let mut x = P::BaseField::zero();
+    while points.len() < n {
+        if let Some(point) = Affine::<P>::get_point_from_x_unchecked(x, false) {
+            if !point.is_zero() {
+                points.push(point);
+            }
+        }
+        x.add_assign(P::BaseField::one());
+    }
+    points
+}
+
+fn is_prime(n: usize) -> bool {
+    n >= 2
+        && (2..n)
+            .take_while(|d| d * d <= n)
+            .all(|d| !n.is_multiple_of(d))
+}
@@ -0,0 +1,220 @@
+use ark_ec::short_weierstrass::SWCurveConfig;
+use ark_ff::{Field, MontFp, One, PrimeField, Zero};
+use ark_secp256k1::Fq;
+use ark_std::test_rng;
+use ark_std::UniformRand;
+use elliptic_curve::hash_to_curve::optimized_swu::pallas_oswu::PallasOSWUMap;
+use elliptic_curve::hash_to_curve::optimized_swu::secp256K1_oswu::Secp256K1OSWUMap;
+use elliptic_curve::hash_to_curve::optimized_swu::vesta_oswu::VestaOSWUMap;
+use elliptic_curve::hash_to_curve::optimized_swu::OptimizedSWUMap;
+use elliptic_curve::hash_to_curve::simplified_swu::secp256K1_sswu::{Secp256K1SSWUMap, ISOGENY};
+use elliptic_curve::hash_to_curve::simplified_swu::SimplifiedSWUMap;
+use elliptic_curve::hash_to_curve::z_selection::find_z_sswu_with_coeffs;
+
+use crate::emit::{Instance, Suite, XmdHash};
+use crate::poly;
+use crate::search::{find_isogenous_curves, isogeny_map, IsogenousCurve};
+use crate::velu::Velu;
+
+/// Check that the isogeny agrees with the Jacobian `isogeny_map` of an instance
+/// on the random points of the isogenous curve y^2 = x^3 + a * x + b
+fn check_against_instance<P: SWCurveConfig>(
+    isogeny: &Velu<P::BaseField>,
+    a: &P::BaseField,
+    b: &P::BaseField,
+    instance: impl Fn(
+        &P::BaseField,
+        &P::BaseField,
+        &P::BaseField,
+    ) -> (P::BaseField, P::BaseField, P::BaseField),
+) where
+    P::BaseField: PrimeField,
+{
+    let mut rng = test_rng();
+    let mut count = 0;
+    while count < 20 {
+        let x = P::BaseField::rand(&mut rng);
+        let Some(y) = ((x.square() + a) * x + b).sqrt() else {
+            continue;
+        };
+        count += 1;
+
+        let (x_j, y_j, z_j) = instance(&x, &y, &P::BaseField::one());
+        let z_inv = z_j.inverse().unwrap();
+        assert_eq!(
+            isogeny.map(&x, &y),
+            Some((x_j * z_inv.square(), y_j * z_inv.square() * z_inv))
+        );
+    }
+}
+
+/// Parse the coefficients of the table `name` from the emitted module
+fn emitted_table<F: PrimeField>(module: &str, name: &str) -> Vec<F> {
+    let start = module.find(&format!("const {}: ", name)).unwrap();
+    module[start..]
+        .lines()
+        .skip(1)
+        .take_while(|line| *line != "];")
+        .map(|line| {
+            let value = line
+                .trim()
+                .strip_prefix("MontFp!(\"")
+                .and_then(|line| line.strip_suffix("\"),"))
+                .unwrap();
+            match value.strip_prefix('-') {
+                Some(value) => -F::from_str(value).ok().unwrap(),
+                None => F::from_str(value).ok().unwrap(),
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn test_roots() {
+    let mut rng = test_rng();
+    let mut roots: Vec<Fq> = (0..5).map(|_| Fq::rand(&mut rng)).collect();
+    roots.sort();
+
+    // (x - r_1) * ... * (x - r_5) * (x^2 + 1), where -1 is not a square for p = 3 mod 4
+    let f = poly::mul(
+        &poly::from_roots(&roots),
+        &[Fq::one(), Fq::zero(), Fq::one()],
+    );
+    assert_eq!(poly::roots(&f), roots);
+}
+
+#[test]
+fn test_secp256k1() {
+    let a: Fq =
+        MontFp!("28734576633528757162648956269730739219262246272443394170905244663053633733939");
+    let b: Fq = MontFp!("1771");
+
+    // the 3-isogenous curve of RFC 9380 is one of the Vélu codomains
+    let curves = find_isogenous_curves::<ark_secp256k1::Config>(29);
+    assert_eq!(curves.len(), 3);
+    assert!(curves.contains(&IsogenousCurve { degree: 3, a, b }));
+    assert_eq!(find_z_sswu_with_coeffs(&a, &b), MontFp!("-11"));
+
+    let isogeny = isogeny_map::<ark_secp256k1::Config>(&a, &b, 3).unwrap();
+    check_against_instance::<ark_secp256k1::Config>(&isogeny, &a, &b, |x, y, z| {
+        let point = Secp256K1SSWUMap::isogeny_map(&(*x / z.square()), &(*y / (z.square() * z)));
+        (point.x, point.y, One::one())
+    });
+    check_against_instance::<ark_secp256k1::Config>(
+        &isogeny,
+        &a,
+        &b,
+        Secp256K1OSWUMap::isogeny_map,
+    );
+
+    // the 3-isogeny is not the 5-isogeny
+    assert!(isogeny_map::<ark_secp256k1::Config>(&a, &b, 5).is_none());
+
+    let instance = Instance::<ark_secp256k1::Config> {
+        name: "Secp256K1OSWUMap",
+        curve: "secp256k1",
+        krate: "ark_secp256k1",
+        config: "Config",
+        dst: "secp256k1_oswu",
+        a,
+        b,
+        z: find_z_sswu_with_coeffs(&a, &b),
+        isogeny: &isogeny,
+        hash: XmdHash::Sha256,
+        sec_param: 128,
+        suite: Some(Suite {
+            id: "secp256k1_XMD:SHA-256_SSWU",
+            spec: "RFC 9380",
+        }),
+    };
+    assert_eq!(instance.c2(), Secp256K1OSWUMap::C2);
+
+    // the emitted tables are the ones of RFC 9380 in the crate
+    let module = instance.module();
+    for (name, table) in [
+        ("X_MAP_NUMERATOR", ISOGENY.x_num),
+        ("X_MAP_DENOMINATOR", ISOGENY.x_den),
+        ("Y_MAP_NUMERATOR", ISOGENY.y_num),
+        ("Y_MAP_DENOMINATOR", ISOGENY.y_den),
+    ] {
+        assert_eq!(emitted_table::<Fq>(&module, name), table, "{}", name);
+    }
+
+    assert!(module.contains("use ark_secp256k1::{Config, Fq};"));
+    assert!(module.contains("use sha2::Sha256;"));
+    assert!(module.contains("const Z: Fq = MontFp!(\"-11\");"));
+    assert!(module.contains("const B: Fq = MontFp!(\"1771\");"));
+    assert!(module.contains("impl OptimizedSWUMap<Config> for Secp256K1OSWUMap {"));
+    assert!(module.contains("`secp256k1_XMD:SHA-256_SSWU_RO_`"));
+    assert!(module.contains("type FieldHasher = XmdFieldHasher<Sha256>;"));
+
+    // no suite is named for the other hash functions
+    let module = Instance {
+        hash: XmdHash::Sha512,
+        suite: None,
+        ..instance
+    }
+    .module();
+    assert!(!module.contains("_SSWU_RO_"));
+    assert!(module.contains("use sha2::Sha512;"));
+    assert!(module.contains("type FieldHasher = XmdFieldHasher<Sha512>;"));
+}
+
+#[test]
+fn test_pasta() {
+    // the first Vélu codomains are the isogenous curves of the `pasta_curves` crate
+    let curve = find_isogenous_curves::<ark_pallas::PallasConfig>(29).remove(0);
+    assert_eq!(
+        (curve.degree, curve.a, curve.b),
+        (3, PallasOSWUMap::A, PallasOSWUMap::B)
+    );
+    assert_eq!(
+        find_z_sswu_with_coeffs(&curve.a, &curve.b),
+        PallasOSWUMap::Z
+    );
+
+    let isogeny = isogeny_map::<ark_pallas::PallasConfig>(&curve.a, &curve.b, 3).unwrap();
+    check_against_instance::<ark_pallas::PallasConfig>(
+        &isogeny,
+        &curve.a,
+        &curve.b,
+        PallasOSWUMap::isogeny_map,
+    );
+
+    let module = Instance::<ark_pallas::PallasConfig> {
+        name: "PallasOSWUMap",
+        curve: "pallas",
+        krate: "ark_pallas",
+        config: "PallasConfig",
+        dst: "pallas_oswu",
+        a: curve.a,
+        b: curve.b,
+        z: PallasOSWUMap::Z,
+        isogeny: &isogeny,
+        hash: XmdHash::Blake2b512,
+        sec_param: 256,
+        suite: Some(Suite {
+            id: "pallas_XMD:BLAKE2b_SSWU",
+            spec: "the `pasta_curves` crate",
+        }),
+    }
+    .module();
+    assert!(module.contains("use blake2::Blake2b512;"));
+    assert!(module.contains("`pallas_XMD:BLAKE2b_SSWU_NU_` of the `pasta_curves` crate"));
+    assert!(module.contains("type FieldHasher = XmdFieldHasher<Blake2b512, 256>;"));
+
+    let curve = find_isogenous_curves::<ark_vesta::VestaConfig>(29).remove(0);
+    assert_eq!(
+        (curve.degree, curve.a, curve.b),
+        (3, VestaOSWUMap::A, VestaOSWUMap::B)
+    );
+    assert_eq!(find_z_sswu_with_coeffs(&curve.a, &curve.b), VestaOSWUMap::Z);
+
+    let isogeny = isogeny_map::<ark_vesta::VestaConfig>(&curve.a, &curve.b, 3).unwrap();
+    check_against_instance::<ark_vesta::VestaConfig>(
+        &isogeny,
+        &curve.a,
+        &curve.b,
+        VestaOSWUMap::isogeny_map,
+    );
+}
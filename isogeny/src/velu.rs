@@ -0,0 +1,187 @@
+use ark_ff::{Field, PrimeField};
+
+use crate::poly;
+
+/// The division polynomials f_0, ..., f_n of the curve y^2 = x^3 + a * x + b in x only,
+/// where psi_m = f_m for odd m and psi_m = 2 * y * f_m for even m
+pub fn division_polynomials<F: Field>(a: &F, b: &F, n: usize) -> Vec<Vec<F>> {
+    let a_2 = a.square();
+    // (2 * y)^4 = (4 * (x^3 + a * x + b))^2
+    let y_4 = poly::mul(
+        &[
+            b.double().double(),
+            a.double().double(),
+            F::zero(),
+            F::from(4u64),
+        ],
+        &[
+            b.double().double(),
+            a.double().double(),
+            F::zero(),
+            F::from(4u64),
+        ],
+    );
+
+    let mut f: Vec<Vec<F>> = vec![
+        vec![],
+        vec![F::one()],
+        vec![F::one()],
+        poly::trim(vec![
+            a_2.neg(),
+            b.mul(F::from(12u64)),
+            a.mul(F::from(6u64)),
+            F::zero(),
+            F::from(3u64),
+        ]),
+        poly::trim(vec![
+            a_2.mul(a).add(b.square().mul(F::from(8u64))).double().neg(),
+            a.mul(b).mul(F::from(8u64)).neg(),
+            a_2.mul(F::from(10u64)).neg(),
+            b.mul(F::from(40u64)),
+            a.mul(F::from(10u64)),
+            F::zero(),
+            F::from(2u64),
+        ]),
+    ];
+    for k in 5..=n {
+        let m = k / 2;
+        let next = if k % 2 == 1 {
+            // f_(2m+1) = f_(m+2) * f_m^3 - f_(m-1) * f_(m+1)^3 up to the factor (2 * y)^4
+            let lhs = poly::mul(&f[m + 2], &poly::mul(&f[m], &poly::mul(&f[m], &f[m])));
+            let rhs = poly::mul(
+                &f[m - 1],
+                &poly::mul(&f[m + 1], &poly::mul(&f[m + 1], &f[m + 1])),
+            );
+            if m % 2 == 0 {
+                poly::sub(&poly::mul(&y_4, &lhs), &rhs)
+            } else {
+                poly::sub(&lhs, &poly::mul(&y_4, &rhs))
+            }
+        } else {
+            // f_(2m) = f_m * (f_(m+2) * f_(m-1)^2 - f_(m-2) * f_(m+1)^2)
+            let lhs = poly::mul(&f[m + 2], &poly::mul(&f[m - 1], &f[m - 1]));
+            let rhs = poly::mul(&f[m - 2], &poly::mul(&f[m + 1], &f[m + 1]));
+            poly::mul(&f[m], &poly::sub(&lhs, &rhs))
+        };
+        f.push(next);
+    }
+    f.truncate(n + 1);
+    f
+}
+
+/// The kernels of the isogenies of the odd prime degree `degree` defined over the field,
+/// whose points have the x-coordinates in the field.
+///
+/// Each kernel is given by the x-coordinates of its (degree - 1) / 2 points up to the sign,
+/// which are the roots of the kernel polynomial.
+pub fn kernels<F: PrimeField>(a: &F, b: &F, degree: usize) -> Vec<Vec<F>> {
+    let f = division_polynomials(a, b, degree + 1);
+    // 4 * y^2
+    let y_2 = [
+        b.double().double(),
+        a.double().double(),
+        F::zero(),
+        F::from(4u64),
+    ];
+
+    let mut kernels: Vec<Vec<F>> = vec![];
+    for x0 in poly::roots(&f[degree]) {
+        // x([k]P) = x - psi_(k-1) * psi_(k+1) / psi_k^2
+        let y_2 = poly::evaluate(&y_2, &x0);
+        let mut xs: Vec<F> = (1..=(degree - 1) / 2)
+            .map(|k| {
+                let f_prev = poly::evaluate(&f[k - 1], &x0);
+                let f_k = poly::evaluate(&f[k], &x0);
+                let f_next = poly::evaluate(&f[k + 1], &x0);
+                let ratio = if k % 2 == 1 {
+                    f_prev.mul(&f_next).mul(&y_2).div(f_k.square())
+                } else {
+                    f_prev.mul(&f_next).div(f_k.square().mul(&y_2))
+                };
+                x0.sub(&ratio)
+            })
+            .collect();
+        xs.sort();
+        if !kernels.contains(&xs) {
+            kernels.push(xs);
+        }
+    }
+    kernels
+}
+
+/// The isogeny of Vélu's formulas from y^2 = x^3 + a * x + b with the kernel `kernel`
+/// given by the x-coordinates of the points up to the sign, see <https://eprint.iacr.org/2020/341>
+#[derive(Clone, Debug)]
+pub struct Velu<F: Field> {
+    /// The parameter a of the codomain
+    pub a: F,
+
+    /// The parameter b of the codomain
+    pub b: F,
+
+    /// The rational map (x_num / x_den, y * y_num / y_den),
+    /// the coefficients are in ascending order of the degree and the denominators are monic
+    pub x_num: Vec<F>,
+    pub x_den: Vec<F>,
+    pub y_num: Vec<F>,
+    pub y_den: Vec<F>,
+}
+
+impl<F: Field> Velu<F> {
+    pub fn new(a: &F, b: &F, kernel: &[F]) -> Self {
+        let mut t = F::zero();
+        let mut w = F::zero();
+
+        // X(x) = x + sum(v_Q / (x - x_Q) + u_Q / (x - x_Q)^2) = x_num / D^2
+        let d = poly::from_roots(kernel);
+        let d_2 = poly::mul(&d, &d);
+        let mut x_num = poly::mul(&[F::zero(), F::one()], &d_2);
+        for x_q in kernel {
+            let v_q = x_q.square().mul(F::from(3u64)).add(a).double();
+            let u_q = x_q.square().add(a).mul(x_q).add(b).double().double();
+            t.add_assign(&v_q);
+            w.add_assign(u_q.add(x_q.mul(&v_q)));
+
+            let (d_q, _) = poly::div_rem(&d, &[x_q.neg(), F::one()]);
+            let term = poly::mul(&[u_q.sub(v_q.mul(x_q)), v_q], &poly::mul(&d_q, &d_q));
+            x_num = poly::add(&x_num, &term);
+        }
+
+        // Y(x, y) = y * X'(x) = y * (x_num' * D - 2 * x_num * D') / D^3
+        let y_num = poly::sub(
+            &poly::mul(&poly::derivative(&x_num), &d),
+            &poly::mul(&x_num, &poly::derivative(&d))
+                .iter()
+                .map(|c| c.double())
+                .collect::<Vec<_>>(),
+        );
+        let y_den = poly::mul(&d_2, &d);
+
+        Self {
+            a: a.sub(t.mul(F::from(5u64))),
+            b: b.sub(w.mul(F::from(7u64))),
+            x_num,
+            x_den: d_2,
+            y_num,
+            y_den,
+        }
+    }
+
+    /// Compose with the isomorphism (x, y) -> (s * x, u * y) of the codomain, where s = u^2
+    pub fn scale(&mut self, s: &F, u: &F) {
+        self.a.mul_assign(s.square());
+        self.b.mul_assign(s.square().mul(s));
+        self.x_num = poly::scale(&self.x_num, s);
+        self.y_num = poly::scale(&self.y_num, u);
+    }
+
+    /// Map the affine point (x, y), which returns `None` for the points in the kernel
+    pub fn map(&self, x: &F, y: &F) -> Option<(F, F)> {
+        let x_den = poly::evaluate(&self.x_den, x).inverse()?;
+        let y_den = poly::evaluate(&self.y_den, x).inverse()?;
+        Some((
+            poly::evaluate(&self.x_num, x).mul(x_den),
+            poly::evaluate(&self.y_num, x).mul(y).mul(y_den),
+        ))
+    }
+}
@@ -0,0 +1,9 @@
+#!/bin/sh
+# Build elliptic-curve without std for a bare-metal target, as the `no_std` job of CI does.
+# Extra arguments are passed to cargo, e.g. `scripts/check-no-std.sh --verbose`.
+set -e
+
+TARGET=thumbv7em-none-eabi
+
+rustup target add "$TARGET"
+cargo build -p elliptic-curve --no-default-features --features alloc --target "$TARGET" "$@"
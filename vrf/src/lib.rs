@@ -7,7 +7,8 @@ use ark_ff::{
 use ark_serialize::CanonicalSerialize;
 use ark_std::rand::Rng;
 use ark_std::UniformRand;
-use elliptic_curve::hash_to_curve::sw_map::SWMap;
+use elliptic_curve::hash_to_curve::field_hasher::XmdFieldHasher;
+use elliptic_curve::hash_to_curve::hasher::HashToCurve;
 use proof::VRFProof;
 use sha2::{digest::DynDigest, Sha256};
 use std::{ops::*, vec};
@@ -36,13 +37,13 @@ impl<P: SWCurveConfig> KeyPair<P> {
     }
 
     // Verifiable Random Function
-    fn vrf<H: SWMap<P>, R: Rng>(&self, seed: &[u8], prng: &mut R) -> VRFProof<P> {
+    fn vrf<H: HashToCurve<Projective<P>>, R: Rng>(&self, seed: &[u8], prng: &mut R) -> VRFProof<P> {
         let mut buf = Vec::new();
         self.public_key.serialize_uncompressed(&mut buf).unwrap();
         buf.extend_from_slice(seed);
 
         // 1. h = HTC(PK || seed)
-        let h: Projective<P> = H::hash::<Sha256>(&buf);
+        let h: Projective<P> = H::hash::<XmdFieldHasher<Sha256>>(&buf);
 
         // 2. gamma = h * SK
         let gamma = h.mul(&self.private_key);
@@ -2,7 +2,8 @@ use std::ops::{Add, Mul};
 
 use ark_ec::short_weierstrass::{Projective, SWCurveConfig};
 use ark_serialize::CanonicalSerialize;
-use elliptic_curve::hash_to_curve::sw_map::SWMap;
+use elliptic_curve::hash_to_curve::field_hasher::XmdFieldHasher;
+use elliptic_curve::hash_to_curve::hasher::HashToCurve;
 use sha2::Sha256;
 
 use crate::KeyPair;
@@ -16,12 +17,12 @@ pub struct VRFProof<P: SWCurveConfig> {
 }
 
 impl<P: SWCurveConfig> VRFProof<P> {
-    pub fn verify<H: SWMap<P>>(&self, pk: &Projective<P>) -> bool {
+    pub fn verify<H: HashToCurve<Projective<P>>>(&self, pk: &Projective<P>) -> bool {
         let mut buf = Vec::new();
         pk.serialize_uncompressed(&mut buf).unwrap();
         buf.extend_from_slice(&self.seed);
 
-        let h = H::hash::<Sha256>(&buf);
+        let h = H::hash::<XmdFieldHasher<Sha256>>(&buf);
 
         // u = c * PK + s * G
         let u: Projective<P> = pk.mul(&self.c).add(P::GENERATOR.mul(&self.s));
@@ -49,10 +50,6 @@ impl<P: SWCurveConfig> VRFProof<P> {
 
         let c = KeyPair::<P>::hash_to_field(&buf);
 
-        if c == self.c {
-            true
-        } else {
-            false
-        }
+        c == self.c
     }
 }
@@ -4,7 +4,10 @@ use ark_ec::{AffineRepr, Group};
 use ark_ff::MontFp;
 use ark_secp256k1::Fq;
 use ark_std::test_rng;
+use elliptic_curve::hash_to_curve::simplified_swu::secp256K1_sswu::Secp256K1SSWUMap;
+use elliptic_curve::hash_to_curve::simplified_swu::SimplifiedSWUHashToCurve;
 use elliptic_curve::hash_to_curve::sw_map::secp256K1_sw::Secp256K1SWMap;
+use elliptic_curve::hash_to_curve::sw_map::SWHashToCurve;
 
 use crate::KeyPair;
 
@@ -26,7 +29,20 @@ fn test_vrf() {
 
     let seed = b"I am a seed";
 
-    let proof = key_pair.vrf::<Secp256K1SWMap, _>(seed, &mut prng);
+    let proof = key_pair.vrf::<SWHashToCurve<Secp256K1SWMap>, _>(seed, &mut prng);
 
-    assert!(proof.verify::<Secp256K1SWMap>(&key_pair.public_key))
+    assert!(proof.verify::<SWHashToCurve<Secp256K1SWMap>>(&key_pair.public_key))
+}
+
+#[test]
+fn test_vrf_with_sswu() {
+    let mut prng = test_rng();
+    let key_pair = KeyPair::<ark_secp256k1::Config>::new(&mut prng);
+
+    let seed = b"I am a seed";
+
+    let proof = key_pair.vrf::<SimplifiedSWUHashToCurve<Secp256K1SSWUMap>, _>(seed, &mut prng);
+
+    assert!(proof.verify::<SimplifiedSWUHashToCurve<Secp256K1SSWUMap>>(&key_pair.public_key));
+    assert!(!proof.verify::<SWHashToCurve<Secp256K1SWMap>>(&key_pair.public_key))
 }